@@ -0,0 +1,85 @@
+import { useState } from "react";
+import { useWallet } from "@solana/wallet-adapter-react";
+import { useSolanaService } from "~/hooks/useSolanaService";
+import { Handshake } from "@phosphor-icons/react";
+import { OnChainTaskData } from "~/types/blockchain";
+
+interface AssignmentResponseProps {
+  taskId: string;
+  creatorWalletAddress: string;
+  volunteerWalletAddress: string;
+  onChainTask: OnChainTaskData;
+  onResponded?: () => void;
+}
+
+/** Lets an invited volunteer accept or decline their on-chain task assignment */
+export function AssignmentResponse({
+  taskId,
+  creatorWalletAddress,
+  volunteerWalletAddress,
+  onChainTask,
+  onResponded,
+}: AssignmentResponseProps) {
+  const { publicKey } = useWallet();
+  const { taskRewardService } = useSolanaService();
+  const [isResponding, setIsResponding] = useState(false);
+
+  const isInvited = onChainTask.invitations.some(
+    (invitation) => invitation.assignee.toBase58() === volunteerWalletAddress,
+  );
+  if (!isInvited) return null;
+
+  const wrongWallet = publicKey?.toBase58() !== volunteerWalletAddress;
+
+  const handleRespond = async (accept: boolean) => {
+    if (!taskRewardService) return;
+
+    setIsResponding(true);
+    try {
+      const txSignature = await taskRewardService.respondToAssignment(
+        taskId,
+        creatorWalletAddress,
+        accept,
+      );
+      if (txSignature) onResponded?.();
+    } finally {
+      setIsResponding(false);
+    }
+  };
+
+  return (
+    <div className="bg-accentPrimary/10 border border-accentPrimary/20 rounded-lg p-4">
+      <div className="flex items-center gap-2 mb-2">
+        <Handshake size={20} className="text-accentPrimary" />
+        <h3 className="font-medium text-baseSecondary">Confirm Assignment</h3>
+      </div>
+      <p className="text-baseSecondary/70 text-sm mb-3">
+        The charity has assigned you to this task. Accept the assignment to
+        start working on it and become eligible for its reward.
+      </p>
+      <div className="flex gap-2">
+        <button
+          onClick={() => handleRespond(true)}
+          disabled={isResponding || wrongWallet}
+          aria-label="Accept assignment"
+          className="rounded-lg px-3 py-1 text-sm font-medium bg-confirmPrimary text-white disabled:opacity-50"
+        >
+          {isResponding ? "Saving..." : "Accept"}
+        </button>
+        <button
+          onClick={() => handleRespond(false)}
+          disabled={isResponding || wrongWallet}
+          aria-label="Decline assignment"
+          className="rounded-lg px-3 py-1 text-sm font-medium border border-baseSecondary/20 text-baseSecondary disabled:opacity-50"
+        >
+          Decline
+        </button>
+      </div>
+      {wrongWallet && (
+        <p className="text-xs text-baseSecondary/60 mt-2">
+          Connect the wallet you used when applying to respond.
+        </p>
+      )}
+    </div>
+  );
+}
@@ -31,6 +31,12 @@ import { useSolanaService } from "~/hooks/useSolanaService";
 import { useAnchorWallet } from "@solana/wallet-adapter-react";
 import { toast } from "react-toastify";
 import { ClaimRewardButton } from "../tasks/ClaimRewardButton";
+import {
+  WorkSubmissionPanel,
+  SubmissionReviewPanel,
+  isSubmissionApproved,
+} from "../tasks/TaskSubmissions";
+import { AssignmentResponse } from "../tasks/AssignmentResponse";
 
 interface TaskDetailsProps {
   task: tasks & {
@@ -75,6 +81,8 @@ export function TaskDetails({
   const [isRetryingEscrow, setIsRetryingEscrow] = useState(false);
   const [isUpdatingReward, setIsUpdatingReward] = useState(false);
   const [isAddingWallet, setIsAddingWallet] = useState(false);
+  // Bumped after on-chain actions so the task account is re-fetched
+  const [onChainRefresh, setOnChainRefresh] = useState(0);
   const { taskEscrowService, blockchainReader, taskRewardService } =
     useSolanaService();
   const wallet = useAnchorWallet();
@@ -133,7 +141,13 @@ export function TaskDetails({
       }
     };
     getOnChainTask();
-  }, [task.id, task.rewardAmount, task.creatorWalletAddress, blockchainReader]);
+  }, [
+    task.id,
+    task.rewardAmount,
+    task.creatorWalletAddress,
+    blockchainReader,
+    onChainRefresh,
+  ]);
 
   const handleRetryEscrow = async () => {
     if (!taskEscrowService || !task.id || !task.rewardAmount) {
@@ -155,6 +169,7 @@ export function TaskDetails({
         rewardAmount: task.rewardAmount,
         creatorWallet: task.creatorWalletAddress,
         mintAddress: faucetInfo.mint,
+        deadline: task.deadline,
       });
 
       if (txSignature) {
@@ -1129,6 +1144,30 @@ export function TaskDetails({
                     </div>
                   )}
 
+                  {/* Submission review for Charities */}
+                  {userRole.includes("charity") &&
+                    onChainTask &&
+                    task.creatorWalletAddress && (
+                      <div className="mt-3 sm:mt-4">
+                        <h3 className="text-xs font-medium tracking-wider text-baseSecondary/70 mb-2 flex items-center gap-1">
+                          <Info
+                            size={16}
+                            weight="regular"
+                            className="text-baseSecondary/70"
+                          />
+                          Submissions
+                        </h3>
+                        <SubmissionReviewPanel
+                          taskId={task.id}
+                          creatorWalletAddress={task.creatorWalletAddress}
+                          onChainTask={onChainTask}
+                          onReviewed={() =>
+                            setOnChainRefresh((count) => count + 1)
+                          }
+                        />
+                      </div>
+                    )}
+
                   {userRole.includes("charity") && (
                     <div className="mt-3 sm:mt-4">
                       <h3
@@ -1241,12 +1280,58 @@ export function TaskDetails({
                         )}
                         {currentApplication.status === "ACCEPTED" && (
                           <div className="space-y-2">
-                            {/* Show claim reward button if task is completed and volunteer has wallet address */}
+                            {/* Invited volunteers must accept before they count as assignees */}
+                            {onChainTask &&
+                              task.creatorWalletAddress &&
+                              task.taskApplications[0]
+                                ?.volunteerWalletAddress && (
+                                <AssignmentResponse
+                                  taskId={task.id}
+                                  creatorWalletAddress={
+                                    task.creatorWalletAddress
+                                  }
+                                  volunteerWalletAddress={
+                                    task.taskApplications[0]
+                                      .volunteerWalletAddress
+                                  }
+                                  onChainTask={onChainTask}
+                                  onResponded={() =>
+                                    setOnChainRefresh((count) => count + 1)
+                                  }
+                                />
+                              )}
+
+                            {/* Let the volunteer submit their work for the creator to review */}
+                            {onChainTask &&
+                              task.creatorWalletAddress &&
+                              task.taskApplications[0]
+                                ?.volunteerWalletAddress && (
+                                <WorkSubmissionPanel
+                                  taskId={task.id}
+                                  creatorWalletAddress={
+                                    task.creatorWalletAddress
+                                  }
+                                  volunteerWalletAddress={
+                                    task.taskApplications[0]
+                                      .volunteerWalletAddress
+                                  }
+                                  onChainTask={onChainTask}
+                                  onSubmitted={() =>
+                                    setOnChainRefresh((count) => count + 1)
+                                  }
+                                />
+                              )}
+
+                            {/* Show claim reward button once the task is completed and the submission approved */}
                             {task.status === "COMPLETED" &&
                               task.taskApplications[0]
                                 ?.volunteerWalletAddress &&
                               JSON.stringify(onChainTask?.status) ===
-                                JSON.stringify({ completed: {} }) && (
+                                JSON.stringify({ completed: {} }) &&
+                              isSubmissionApproved(
+                                onChainTask,
+                                task.taskApplications[0].volunteerWalletAddress,
+                              ) && (
                                 <ClaimRewardButton
                                   task={{
                                     id: task.id,
@@ -0,0 +1,253 @@
+import { useState } from "react";
+import { useWallet } from "@solana/wallet-adapter-react";
+import { useSolanaService } from "~/hooks/useSolanaService";
+import { CheckCircle, Clock, UploadSimple } from "@phosphor-icons/react";
+import { OnChainTaskData } from "~/types/blockchain";
+import { hashDeliverable } from "~/services/task-reward.client";
+
+type OnChainSubmission = OnChainTaskData["submissions"][number];
+
+// Anchor decodes enums as objects keyed by the variant name
+const submissionStatusLabel = (submission?: OnChainSubmission) => {
+  if (!submission || "notSubmitted" in submission.status) return "Not submitted";
+  if ("submitted" in submission.status) return "Awaiting review";
+  if ("changesRequested" in submission.status) return "Changes requested";
+  return "Approved";
+};
+
+const contentHashHex = (submission: OnChainSubmission) =>
+  submission.contentHash
+    .map((byte) => byte.toString(16).padStart(2, "0"))
+    .join("");
+
+export const isSubmissionApproved = (
+  onChainTask: OnChainTaskData | null,
+  volunteerWalletAddress?: string,
+) => {
+  if (!onChainTask || !volunteerWalletAddress) return false;
+  const index = onChainTask.assignees.findIndex(
+    (assignee) => assignee.toBase58() === volunteerWalletAddress,
+  );
+  const submission = onChainTask.submissions[index];
+  return !!submission && "approved" in submission.status;
+};
+
+interface WorkSubmissionPanelProps {
+  taskId: string;
+  creatorWalletAddress: string;
+  volunteerWalletAddress: string;
+  onChainTask: OnChainTaskData;
+  onSubmitted?: () => void;
+}
+
+/** Lets an assigned volunteer submit their deliverable for review */
+export function WorkSubmissionPanel({
+  taskId,
+  creatorWalletAddress,
+  volunteerWalletAddress,
+  onChainTask,
+  onSubmitted,
+}: WorkSubmissionPanelProps) {
+  const { publicKey } = useWallet();
+  const { taskRewardService } = useSolanaService();
+  const [uri, setUri] = useState("");
+  const [deliverable, setDeliverable] = useState<File | null>(null);
+  const [isSubmitting, setIsSubmitting] = useState(false);
+
+  const index = onChainTask.assignees.findIndex(
+    (assignee) => assignee.toBase58() === volunteerWalletAddress,
+  );
+  if (index === -1) return null;
+
+  const submission = onChainTask.submissions[index];
+  const canSubmit =
+    !submission ||
+    "notSubmitted" in submission.status ||
+    "changesRequested" in submission.status;
+
+  const handleSubmit = async () => {
+    if (!taskRewardService || !uri.trim() || !deliverable) return;
+
+    setIsSubmitting(true);
+    try {
+      // Hash the file itself so the creator can check the linked copy matches
+      const txSignature = await taskRewardService.submitWork(
+        taskId,
+        creatorWalletAddress,
+        uri.trim(),
+        await hashDeliverable(deliverable),
+      );
+      if (txSignature) {
+        setUri("");
+        setDeliverable(null);
+        onSubmitted?.();
+      }
+    } finally {
+      setIsSubmitting(false);
+    }
+  };
+
+  return (
+    <div className="bg-basePrimaryLight border border-baseSecondary/20 rounded-lg p-4">
+      <div className="flex items-center gap-2 mb-2">
+        <UploadSimple size={20} className="text-baseSecondary" />
+        <h3 className="font-medium text-baseSecondary">Your Submission</h3>
+      </div>
+      <p className="text-baseSecondary/70 text-sm mb-2">
+        Status:{" "}
+        <span className="font-semibold">
+          {submissionStatusLabel(submission)}
+        </span>
+      </p>
+      {submission?.uri && (
+        <a
+          href={submission.uri}
+          target="_blank"
+          rel="noopener noreferrer"
+          className="block text-xs font-mono text-accentPrimary break-all mb-2"
+        >
+          {submission.uri}
+        </a>
+      )}
+      {canSubmit && (
+        <div className="flex flex-col gap-2">
+          <input
+            type="url"
+            value={uri}
+            maxLength={200}
+            onChange={(e) => setUri(e.target.value)}
+            placeholder="Link to your deliverable"
+            aria-label="Deliverable link"
+            className="w-full rounded-lg border border-baseSecondary/20 bg-basePrimary px-3 py-2 text-sm text-baseSecondary"
+          />
+          <input
+            type="file"
+            onChange={(e) => setDeliverable(e.target.files?.[0] ?? null)}
+            aria-label="Deliverable file"
+            className="text-sm text-baseSecondary"
+          />
+          <p className="text-xs text-baseSecondary/60">
+            Select the same file you linked; its fingerprint is recorded with
+            your submission.
+          </p>
+          <button
+            onClick={handleSubmit}
+            disabled={
+              isSubmitting ||
+              !uri.trim() ||
+              !deliverable ||
+              publicKey?.toBase58() !== volunteerWalletAddress
+            }
+            aria-label="Submit work"
+            className="w-fit rounded-lg text-sm font-medium transition-all animated-border-btn duration-300"
+          >
+            <span>{isSubmitting ? "Submitting..." : "Submit Work"}</span>
+          </button>
+          {publicKey?.toBase58() !== volunteerWalletAddress && (
+            <p className="text-xs text-baseSecondary/60">
+              Connect the wallet you used when applying to submit your work.
+            </p>
+          )}
+        </div>
+      )}
+    </div>
+  );
+}
+
+interface SubmissionReviewPanelProps {
+  taskId: string;
+  creatorWalletAddress: string;
+  onChainTask: OnChainTaskData;
+  onReviewed?: () => void;
+}
+
+/** Lets the task creator approve submissions or send them back for changes */
+export function SubmissionReviewPanel({
+  taskId,
+  creatorWalletAddress,
+  onChainTask,
+  onReviewed,
+}: SubmissionReviewPanelProps) {
+  const { taskRewardService } = useSolanaService();
+  const [reviewing, setReviewing] = useState<string | null>(null);
+
+  if (onChainTask.assignees.length === 0) return null;
+
+  const handleReview = async (volunteer: string, approve: boolean) => {
+    if (!taskRewardService) return;
+
+    setReviewing(volunteer);
+    try {
+      const txSignature = await taskRewardService.reviewSubmission(
+        taskId,
+        volunteer,
+        creatorWalletAddress,
+        approve,
+      );
+      if (txSignature) onReviewed?.();
+    } finally {
+      setReviewing(null);
+    }
+  };
+
+  return (
+    <div className="bg-basePrimary rounded-lg p-3 border border-baseSecondary/10 flex flex-col gap-3">
+      {onChainTask.assignees.map((assignee, index) => {
+        const volunteer = assignee.toBase58();
+        const submission = onChainTask.submissions[index];
+        const awaitingReview = !!submission && "submitted" in submission.status;
+
+        return (
+          <div key={volunteer} className="flex flex-col gap-1">
+            <span className="text-xs font-mono text-baseSecondary break-all">
+              {volunteer}
+            </span>
+            <span className="text-sm text-baseSecondary flex items-center gap-1">
+              {submission && "approved" in submission.status ? (
+                <CheckCircle size={14} className="text-confirmPrimary" />
+              ) : (
+                <Clock size={14} className="text-baseSecondary/70" />
+              )}
+              {submissionStatusLabel(submission)}
+            </span>
+            {submission?.uri && (
+              <a
+                href={submission.uri}
+                target="_blank"
+                rel="noopener noreferrer"
+                className="text-xs font-mono text-accentPrimary break-all"
+              >
+                {submission.uri}
+              </a>
+            )}
+            {submission?.uri && (
+              <span className="text-xs font-mono text-baseSecondary/60 break-all">
+                SHA-256: {contentHashHex(submission)}
+              </span>
+            )}
+            {awaitingReview && (
+              <div className="flex gap-2 mt-1">
+                <button
+                  onClick={() => handleReview(volunteer, true)}
+                  disabled={reviewing !== null}
+                  aria-label="Approve submission"
+                  className="rounded-lg px-3 py-1 text-xs font-medium bg-confirmPrimary text-white disabled:opacity-50"
+                >
+                  {reviewing === volunteer ? "Saving..." : "Approve"}
+                </button>
+                <button
+                  onClick={() => handleReview(volunteer, false)}
+                  disabled={reviewing !== null}
+                  aria-label="Request changes"
+                  className="rounded-lg px-3 py-1 text-xs font-medium border border-baseSecondary/20 text-baseSecondary disabled:opacity-50"
+                >
+                  Request Changes
+                </button>
+              </div>
+            )}
+          </div>
+        );
+      })}
+    </div>
+  );
+}
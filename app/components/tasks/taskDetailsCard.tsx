@@ -205,6 +205,7 @@ export default function TaskDetailsCard(props: CombinedTaskDetailsCardProps) {
         rewardAmount: taskData.rewardAmount,
         creatorWallet: taskData.creatorWalletAddress || "",
         mintAddress: faucetInfo.mint,
+        deadline: taskData.deadline,
       });
 
       if (txSignature) {
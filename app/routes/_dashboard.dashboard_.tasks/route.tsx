@@ -258,6 +258,7 @@ export default function ManageTasks() {
                 rewardAmount: task.rewardAmount || 0,
                 creatorWallet: wallet?.publicKey?.toBase58() || "",
                 mintAddress: faucetInfo?.mint || "",
+                deadline: task.deadline,
               });
 
               if (txSignature) {
@@ -8,7 +8,19 @@ import {
   address,
 } from "@solana/kit";
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey, SystemProgram, Connection } from "@solana/web3.js";
+import {
+  PublicKey,
+  SystemProgram,
+  Connection,
+  AccountMeta,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  createTransferCheckedWithTransferHookInstruction,
+  getMint,
+  getTransferHook,
+} from "@solana/spl-token";
 import { getBurnCheckedInstruction } from "@solana-program/token-2022";
 import idl from "../../target/idl/altruvist.json"; // eslint-disable-line
 import { toast } from "react-toastify";
@@ -22,6 +34,18 @@ interface FaucetAccount {
   tokenAccount: PublicKey;
   rateLimit: anchor.BN;
   cooldownPeriod: anchor.BN;
+  lifetimeCap: anchor.BN | null;
+  windowCap: anchor.BN | null;
+  windowPeriod: anchor.BN;
+  merkleRoot: number[] | null;
+  voucherSigner: PublicKey | null;
+  paused: boolean;
+  maxSupply: anchor.BN | null;
+  totalMinted: anchor.BN;
+  reservedBudget: anchor.BN;
+  nonTransferable: boolean;
+  transferRestricted: boolean;
+  pendingAuthority: PublicKey | null;
   bump: number;
 }
 
@@ -54,7 +78,7 @@ export function createClient(): Client {
 client = createClient();
 
 // Token program constants
-const TOKEN_2022_PROGRAM_ID = new PublicKey(
+export const TOKEN_2022_PROGRAM_ID = new PublicKey(
   "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb",
 );
 const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
@@ -158,6 +182,10 @@ export class SolanaService {
     symbol: string,
     uri: string,
     initialSupply: number,
+    rateLimit: number = 1000,
+    cooldownPeriodSeconds: number = 86400,
+    maxSupply: number | null = null,
+    decimals: number = 6,
   ): Promise<string | undefined> {
     if (!this.wallet || !this.wallet.publicKey) {
       toast.error("Wallet not connected");
@@ -183,7 +211,8 @@ export class SolanaService {
       );
       console.log(`faucetTokenAccount: ${faucetTokenAccount.toBase58()}`);
 
-      const initialSupplyWithDecimals = initialSupply * Math.pow(10, 6); // 6 decimals
+      const initialSupplyWithDecimals = initialSupply * Math.pow(10, decimals);
+      const rateLimitWithDecimals = rateLimit * Math.pow(10, decimals);
 
       const txSignature = await this.program.methods
         .initializeFaucet(
@@ -192,9 +221,22 @@ export class SolanaService {
           symbol,
           uri,
           new anchor.BN(initialSupplyWithDecimals),
+          new anchor.BN(rateLimitWithDecimals),
+          new anchor.BN(cooldownPeriodSeconds),
+          maxSupply === null
+            ? null
+            : new anchor.BN(maxSupply * Math.pow(10, decimals)),
+          decimals,
+          {
+            permanentDelegate: null,
+            defaultFrozen: false,
+            nonTransferable: false,
+            transferHook: false,
+          },
         )
         .accounts({
           mint: mintKeypair.publicKey,
+          faucetTokenAccount,
           payer: this.wallet.publicKey,
         })
         .signers([mintKeypair])
@@ -272,6 +314,70 @@ export class SolanaService {
     }
   }
 
+  /**
+   * Resolve the extra accounts a transfer-hook mint needs for a transfer from
+   * `source` to `destination` (empty when the mint has no transfer hook)
+   */
+  private async resolveTransferHookAccounts(
+    mint: PublicKey,
+    source: PublicKey,
+    destination: PublicKey,
+    owner: PublicKey,
+    amount: number,
+  ): Promise<AccountMeta[]> {
+    const connection = this.provider.connection;
+    const mintInfo = await getMint(
+      connection,
+      mint,
+      "confirmed",
+      TOKEN_2022_PROGRAM_ID,
+    );
+    if (!getTransferHook(mintInfo)) {
+      return [];
+    }
+
+    // The hook's accounts are derived from the destination's owner, so the
+    // destination token account has to exist before they can be resolved
+    if (!(await connection.getAccountInfo(destination, "confirmed"))) {
+      const destinationOwner = this.wallet?.publicKey;
+      if (!destinationOwner) {
+        throw new Error("Wallet not connected");
+      }
+      const transaction = new Transaction().add(
+        createAssociatedTokenAccountIdempotentInstruction(
+          destinationOwner,
+          destination,
+          destinationOwner,
+          mint,
+          TOKEN_2022_PROGRAM_ID,
+          ASSOCIATED_TOKEN_PROGRAM_ID,
+        ),
+      );
+      await this.provider.sendAndConfirm(transaction, [], {
+        commitment: "confirmed",
+      });
+    }
+
+    const transferInstruction =
+      await createTransferCheckedWithTransferHookInstruction(
+        connection,
+        source,
+        mint,
+        destination,
+        owner,
+        BigInt(amount),
+        mintInfo.decimals,
+        [],
+        "confirmed",
+        TOKEN_2022_PROGRAM_ID,
+      );
+
+    // Skip the transfer's own source, mint, destination and owner accounts
+    return transferInstruction.keys
+      .slice(4)
+      .map((key) => ({ ...key, isSigner: false }));
+  }
+
   async requestTokens(
     faucetSeed: string,
     mintAddress: string,
@@ -293,8 +399,18 @@ export class SolanaService {
         this.program.programId,
       );
 
-      // Derive user request record PDA
+      // Derive per-faucet user request record PDA
       const [userRequestRecord] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("user_record"),
+          faucetPda.toBuffer(),
+          this.wallet.publicKey.toBuffer(),
+        ],
+        this.program.programId,
+      );
+
+      // Pre-migration record; the program rejects requests while it still exists
+      const [legacyUserRequestRecord] = PublicKey.findProgramAddressSync(
         [Buffer.from("user_record"), this.wallet.publicKey.toBuffer()],
         this.program.programId,
       );
@@ -312,12 +428,23 @@ export class SolanaService {
 
       const amountWithDecimals = amount * Math.pow(10, 6); // 6 decimals
 
+      // Transfer-hook mints need the hook's extra accounts passed through
+      const transferHookAccounts = await this.resolveTransferHookAccounts(
+        mintPubkey,
+        faucetTokenAccount,
+        userTokenAccount,
+        faucetPda,
+        amountWithDecimals,
+      );
+
       const txSignature = await this.program.methods
         .requestTokens(faucetSeed, new anchor.BN(amountWithDecimals))
+        .remainingAccounts(transferHookAccounts)
         .accounts({
           faucet: faucetPda,
           faucetTokenAccount: faucetTokenAccount,
           userRequestRecord: userRequestRecord,
+          legacyUserRequestRecord: legacyUserRequestRecord,
           userTokenAccount: userTokenAccount,
           mint: mintPubkey,
           user: this.wallet.publicKey,
@@ -462,7 +589,7 @@ export class SolanaService {
           faucet: faucetPda,
           faucetTokenAccount: faucetTokenAccount,
           mint: mintPubkey,
-          payer: this.wallet.publicKey,
+          authority: this.wallet.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -527,7 +654,7 @@ export class SolanaService {
           faucet: faucetPda,
           faucetTokenAccount: faucetTokenAccount,
           mint: mintPubkey,
-          payer: this.wallet.publicKey,
+          authority: this.wallet.publicKey,
           tokenProgram: TOKEN_2022_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -1,8 +1,12 @@
-import { SolanaService } from "./solana.client";
+import { SolanaService, TOKEN_2022_PROGRAM_ID } from "./solana.client";
 import { BlockchainReaderService } from "./blockchain-reader.client";
 import { toast } from "react-toastify";
 import * as anchor from "@coral-xyz/anchor";
 import { PublicKey, Transaction } from "@solana/web3.js";
+import {
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
 import { Altruvist } from "../../target/types/altruvist"; // eslint-disable-line
 import { type Address } from "@solana/kit";
 
@@ -11,6 +15,7 @@ export interface TaskEscrowData {
   rewardAmount: number;
   creatorWallet: string;
   mintAddress: string;
+  deadline?: string | Date;
 }
 
 export interface SimulationOptions {
@@ -111,12 +116,31 @@ export class TaskEscrowService {
         return undefined;
       }
 
+      // Tasks without a deadline never expire. The program rejects deadlines
+      // that have already passed, so surface those instead of dropping them
+      let deadline: anchor.BN | null = null;
+      if (taskData.deadline) {
+        const deadlineSeconds = Math.floor(
+          new Date(taskData.deadline).getTime() / 1000,
+        );
+        if (Number.isNaN(deadlineSeconds)) {
+          throw new Error("Task deadline is not a valid date");
+        }
+        if (deadlineSeconds <= Math.floor(Date.now() / 1000)) {
+          throw new Error(
+            "Task deadline has already passed; update it before funding the task",
+          );
+        }
+        deadline = new anchor.BN(deadlineSeconds);
+      }
+
       // Create the task escrow using the Solana program
       // This would interact with your Rust program's create_task instruction
       const txSignature = await this.createTaskOnChain(
         taskData.taskId,
         new anchor.BN(rewardAmountWithDecimals),
         faucetInfo.mint,
+        deadline,
         options,
       );
 
@@ -144,6 +168,7 @@ export class TaskEscrowService {
     taskId: string,
     rewardAmount: anchor.BN,
     mintAddress: string,
+    deadline: anchor.BN | null,
     options: SimulationOptions = {},
   ): Promise<string | undefined> {
     try {
@@ -167,10 +192,11 @@ export class TaskEscrowService {
       // Create transaction for simulation
       if (options.simulate) {
         const instruction = await this.solanaService.program.methods
-          .createTask(taskId, rewardAmount)
+          .createTask(taskId, rewardAmount, deadline, null, 0)
           .accounts({
             mint: new PublicKey(mintAddress),
             creator: this.solanaService.wallet.publicKey,
+            tokenProgram: TOKEN_2022_PROGRAM_ID,
           })
           .instruction();
         const transaction = new Transaction();
@@ -204,10 +230,11 @@ export class TaskEscrowService {
 
       // Execute the actual transaction
       const txSignature = await this.solanaService.program.methods
-        .createTask(taskId, rewardAmount)
+        .createTask(taskId, rewardAmount, deadline, null, 0)
         .accounts({
           mint: new PublicKey(mintAddress),
           creator: this.solanaService.wallet.publicKey,
+          tokenProgram: TOKEN_2022_PROGRAM_ID,
         })
         .rpc({
           commitment: "confirmed",
@@ -395,13 +422,49 @@ export class TaskEscrowService {
         throw new Error("Wallet not connected");
       }
 
-      // Get faucet info for mint address
-      const faucetInfo = await this.blockchainReader.getFaucetInfo();
-      if (!faucetInfo) {
-        throw new Error("Failed to get faucet information");
+      // The task records which mint and token program it pays in
+      const taskInfo = await this.blockchainReader.getTaskInfo(
+        taskId,
+        this.solanaService.wallet.publicKey.toBase58(),
+      );
+      if (!taskInfo) {
+        throw new Error("Failed to get task information");
       }
 
-      // Derive task PDA
+      // Cancelling pays the assignees any unclaimed approved milestones and,
+      // once the task is in progress, its kill fee. Their token accounts are
+      // passed in assignee order
+      const creator = this.solanaService.wallet.publicKey;
+      const paysKillFee =
+        "inProgress" in taskInfo.status && taskInfo.killFeeBps > 0;
+      const hasUnclaimedMilestones = taskInfo.milestones.some(
+        (milestone) => "approved" in milestone.status,
+      );
+      const assigneeTokenAccounts =
+        paysKillFee || hasUnclaimedMilestones
+          ? taskInfo.assignees.map((assignee) =>
+              getAssociatedTokenAddressSync(
+                taskInfo.mint,
+                assignee,
+                false,
+                taskInfo.tokenProgram,
+              ),
+            )
+          : [];
+      const remainingAccounts = assigneeTokenAccounts.map((pubkey) => ({
+        pubkey,
+        isSigner: false,
+        isWritable: true,
+      }));
+      const preInstructions = assigneeTokenAccounts.map((account, index) =>
+        createAssociatedTokenAccountIdempotentInstruction(
+          creator,
+          account,
+          taskInfo.assignees[index],
+          taskInfo.mint,
+          taskInfo.tokenProgram,
+        ),
+      );
 
       // Build the transaction instruction for simulation
       if (options.simulate) {
@@ -409,8 +472,10 @@ export class TaskEscrowService {
           .deleteTask(taskId)
           .accounts({
             creator: this.solanaService.wallet.publicKey,
-            mint: new PublicKey(faucetInfo.mint),
+            mint: taskInfo.mint,
+            tokenProgram: taskInfo.tokenProgram,
           })
+          .remainingAccounts(remainingAccounts)
           .instruction();
 
         const transaction = new Transaction();
@@ -420,7 +485,7 @@ export class TaskEscrowService {
           );
         transaction.recentBlockhash = latestBlockhash.blockhash;
         transaction.feePayer = this.solanaService.wallet.publicKey;
-        transaction.add(instruction);
+        transaction.add(...preInstructions, instruction);
 
         const simulationSuccess = await this.simulateTransaction(
           transaction,
@@ -437,8 +502,11 @@ export class TaskEscrowService {
         .deleteTask(taskId)
         .accounts({
           creator: this.solanaService.wallet.publicKey,
-          mint: new PublicKey(faucetInfo.mint),
+          mint: taskInfo.mint,
+          tokenProgram: taskInfo.tokenProgram,
         })
+        .remainingAccounts(remainingAccounts)
+        .preInstructions(preInstructions)
         .rpc({
           commitment: "confirmed",
           preflightCommitment: "confirmed",
@@ -526,10 +594,13 @@ export class TaskEscrowService {
         newRewardAmount * Math.pow(10, 6),
       );
 
-      // Get faucet info for mint address
-      const faucetInfo = await this.blockchainReader.getFaucetInfo();
-      if (!faucetInfo) {
-        throw new Error("Failed to get faucet information");
+      // The task records which mint and token program it pays in
+      const taskInfo = await this.blockchainReader.getTaskInfo(
+        taskId,
+        this.solanaService.wallet.publicKey.toBase58(),
+      );
+      if (!taskInfo) {
+        throw new Error("Failed to get task information");
       }
 
       // Build the transaction instruction for simulation
@@ -537,7 +608,8 @@ export class TaskEscrowService {
         const instruction = await this.solanaService.program.methods
           .updateTaskReward(taskId, newRewardAmountWithDecimals)
           .accounts({
-            mint: new PublicKey(faucetInfo.mint),
+            mint: taskInfo.mint,
+            tokenProgram: taskInfo.tokenProgram,
             creator: this.solanaService.wallet.publicKey,
           })
           .instruction();
@@ -565,7 +637,8 @@ export class TaskEscrowService {
       const txSignature = await this.solanaService.program.methods
         .updateTaskReward(taskId, newRewardAmountWithDecimals)
         .accounts({
-          mint: new PublicKey(faucetInfo.mint),
+          mint: taskInfo.mint,
+          tokenProgram: taskInfo.tokenProgram,
           creator: this.solanaService.wallet.publicKey,
         })
         .rpc({
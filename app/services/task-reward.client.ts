@@ -13,6 +13,15 @@ export interface TaskRewardService {
     creatorWalletAddress: string,
   ): Promise<string | undefined>;
 
+  /**
+   * Accept or decline a pending task assignment as the invited volunteer
+   */
+  respondToAssignment(
+    taskId: string,
+    creatorWalletAddress: string,
+    accept: boolean,
+  ): Promise<string | undefined>;
+
   /**
    * Remove volunteer from task on the blockchain
    * @param taskId - The ID of the task
@@ -34,6 +43,28 @@ export interface TaskRewardService {
     creatorWalletAddress: string,
   ): Promise<string | undefined>;
 
+  /**
+   * Submit the volunteer's deliverable for the task creator to review
+   * @param uri - Where the deliverable can be reviewed (max 200 characters)
+   * @param contentHash - SHA-256 of the deliverable's bytes (see `hashDeliverable`)
+   */
+  submitWork(
+    taskId: string,
+    creatorWalletAddress: string,
+    uri: string,
+    contentHash: Uint8Array,
+  ): Promise<string | undefined>;
+
+  /**
+   * Approve a volunteer's submission, or request changes to it
+   */
+  reviewSubmission(
+    taskId: string,
+    volunteerWalletAddress: string,
+    creatorWalletAddress: string,
+    approve: boolean,
+  ): Promise<string | undefined>;
+
   /**
    * Allow volunteer to claim their token reward
    */
@@ -52,6 +83,15 @@ export interface TaskRewardService {
   ): Promise<boolean>;
 }
 
+/**
+ * SHA-256 of a deliverable's bytes. Recorded on-chain with the submission so the
+ * creator can check that the file behind the URI hasn't been swapped since.
+ */
+export const hashDeliverable = async (deliverable: Blob) =>
+  new Uint8Array(
+    await crypto.subtle.digest("SHA-256", await deliverable.arrayBuffer()),
+  );
+
 export class TaskRewardServiceImpl implements TaskRewardService {
   constructor(
     private solanaService: SolanaService,
@@ -82,7 +122,7 @@ export class TaskRewardServiceImpl implements TaskRewardService {
       const volunteerPubkey = new PublicKey(volunteerWalletAddress);
 
       const txSignature = await this.solanaService.program.methods
-        .assignTask(taskId, volunteerPubkey)
+        .assignTask(taskId, volunteerPubkey, null)
         .accounts({
           creator: this.solanaService.wallet.publicKey,
         })
@@ -94,7 +134,7 @@ export class TaskRewardServiceImpl implements TaskRewardService {
         });
 
       toast.success(
-        `Volunteer assigned successfully! Transaction: ${txSignature}`,
+        `Volunteer invited successfully! They need to accept the assignment. Transaction: ${txSignature}`,
       );
       return txSignature;
     } catch (error) {
@@ -120,9 +160,15 @@ export class TaskRewardServiceImpl implements TaskRewardService {
           );
           if (taskInfo) {
             const volunteerPubkey = new PublicKey(volunteerWalletAddress);
-            const isAssigned = taskInfo.assignees.some(
-              (assignee) => assignee.toBase58() === volunteerPubkey.toBase58(),
-            );
+            const isAssigned =
+              taskInfo.assignees.some(
+                (assignee) =>
+                  assignee.toBase58() === volunteerPubkey.toBase58(),
+              ) ||
+              taskInfo.invitations.some(
+                (invitation) =>
+                  invitation.assignee.toBase58() === volunteerPubkey.toBase58(),
+              );
 
             if (isAssigned) {
               toast.success(
@@ -172,6 +218,56 @@ export class TaskRewardServiceImpl implements TaskRewardService {
     }
   }
 
+  async respondToAssignment(
+    taskId: string,
+    creatorWalletAddress: string,
+    accept: boolean,
+  ): Promise<string | undefined> {
+    try {
+      if (!this.solanaService.wallet?.publicKey) {
+        throw new Error("Volunteer wallet not connected");
+      }
+
+      toast.info(`${accept ? "Accepting" : "Declining"} task assignment...`);
+
+      const creatorPubkey = new PublicKey(creatorWalletAddress);
+      const [taskPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("task"),
+          Buffer.from(taskId),
+          creatorPubkey.toBuffer(),
+        ],
+        this.solanaService.program.programId,
+      );
+
+      const method = accept
+        ? this.solanaService.program.methods.acceptAssignment(taskId)
+        : this.solanaService.program.methods.declineAssignment(taskId);
+      const txSignature = await method
+        .accountsStrict({
+          task: taskPDA,
+          assignee: this.solanaService.wallet.publicKey,
+        })
+        .rpc({
+          commitment: "confirmed",
+          preflightCommitment: "confirmed",
+          skipPreflight: false,
+          maxRetries: 0,
+        });
+
+      toast.success(
+        `Assignment ${accept ? "accepted" : "declined"}! Transaction: ${txSignature}`,
+      );
+      return txSignature;
+    } catch (error) {
+      console.error("Error responding to task assignment:", error);
+      toast.error(
+        `Failed to respond to assignment: ${error instanceof Error ? error.message : String(error)}`,
+      );
+      return undefined;
+    }
+  }
+
   async removeVolunteerFromTask(
     taskId: string,
     volunteerWalletAddress: string,
@@ -408,6 +504,121 @@ export class TaskRewardServiceImpl implements TaskRewardService {
     }
   }
 
+  async submitWork(
+    taskId: string,
+    creatorWalletAddress: string,
+    uri: string,
+    contentHash: Uint8Array,
+  ): Promise<string | undefined> {
+    try {
+      if (!this.solanaService.wallet?.publicKey) {
+        throw new Error("Volunteer wallet not connected");
+      }
+      if (contentHash.length !== 32) {
+        throw new Error("Content hash must be a 32-byte SHA-256 digest");
+      }
+
+      toast.info(`Submitting work...`);
+
+      const [taskPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("task"),
+          Buffer.from(taskId),
+          new PublicKey(creatorWalletAddress).toBuffer(),
+        ],
+        this.solanaService.program.programId,
+      );
+
+      const txSignature = await this.solanaService.program.methods
+        .submitWork(taskId, Array.from(contentHash), uri)
+        .accountsStrict({
+          task: taskPDA,
+          assignee: this.solanaService.wallet.publicKey,
+        })
+        .rpc({
+          commitment: "confirmed",
+          preflightCommitment: "confirmed",
+          skipPreflight: false,
+          maxRetries: 0,
+        });
+
+      toast.success(`Work submitted for review! Transaction: ${txSignature}`);
+      return txSignature;
+    } catch (error) {
+      console.error("Error submitting work:", error);
+      toast.error(
+        `Failed to submit work: ${error instanceof Error ? error.message : String(error)}`,
+      );
+      return undefined;
+    }
+  }
+
+  async reviewSubmission(
+    taskId: string,
+    volunteerWalletAddress: string,
+    creatorWalletAddress: string,
+    approve: boolean,
+  ): Promise<string | undefined> {
+    try {
+      if (!this.solanaService.wallet?.publicKey) {
+        throw new Error("Creator wallet not connected");
+      }
+
+      // Verify the caller is the task creator
+      if (
+        this.solanaService.wallet.publicKey.toBase58() !== creatorWalletAddress
+      ) {
+        throw new Error(
+          "Only task creator can review submissions. Please check your connected wallet account.",
+        );
+      }
+
+      toast.info(approve ? `Approving submission...` : `Requesting changes...`);
+
+      const volunteerPubkey = new PublicKey(volunteerWalletAddress);
+      const [taskPDA] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("task"),
+          Buffer.from(taskId),
+          this.solanaService.wallet.publicKey.toBuffer(),
+        ],
+        this.solanaService.program.programId,
+      );
+
+      const method = approve
+        ? this.solanaService.program.methods.approveSubmission(
+            taskId,
+            volunteerPubkey,
+          )
+        : this.solanaService.program.methods.requestChanges(
+            taskId,
+            volunteerPubkey,
+          );
+      const txSignature = await method
+        .accountsStrict({
+          task: taskPDA,
+          creator: this.solanaService.wallet.publicKey,
+        })
+        .rpc({
+          commitment: "confirmed",
+          preflightCommitment: "confirmed",
+          skipPreflight: false,
+          maxRetries: 0,
+        });
+
+      toast.success(
+        `${approve ? "Submission approved" : "Changes requested"}! Transaction: ${txSignature}`,
+      );
+      return txSignature;
+    } catch (error) {
+      console.error("Error reviewing submission:", error);
+      toast.error(
+        `Failed to review submission: ${error instanceof Error ? error.message : String(error)}`,
+      );
+      return undefined;
+    }
+  }
+
   async claimReward(
     taskId: string,
     creatorWalletAddress: string,
@@ -419,10 +630,13 @@ export class TaskRewardServiceImpl implements TaskRewardService {
 
       toast.info(`Claiming reward for task...`);
 
-      // Get faucet info for mint address
-      const faucetInfo = await this.blockchainReader.getFaucetInfo();
-      if (!faucetInfo) {
-        throw new Error("Failed to get faucet information");
+      // The task records which mint and token program it pays in
+      const taskInfo = await this.blockchainReader.getTaskInfo(
+        taskId,
+        creatorWalletAddress,
+      );
+      if (!taskInfo) {
+        throw new Error("Failed to get task information");
       }
 
       const [taskPDA] = PublicKey.findProgramAddressSync(
@@ -438,7 +652,8 @@ export class TaskRewardServiceImpl implements TaskRewardService {
         .claimReward(taskId)
         .accounts({
           creator: new PublicKey(creatorWalletAddress),
-          mint: new PublicKey(faucetInfo.mint),
+          mint: taskInfo.mint,
+          tokenProgram: taskInfo.tokenProgram,
           assignee: this.solanaService.wallet.publicKey,
           task: taskPDA,
         })
@@ -503,6 +718,15 @@ export class TaskRewardServiceImpl implements TaskRewardService {
         return false;
       }
 
+      // Rewards can only be claimed once the creator approves the submission
+      const assigneeIndex = taskInfo.assignees.findIndex(
+        (assignee) => assignee.toBase58() === volunteerPubkey.toBase58(),
+      );
+      const submission = taskInfo.submissions[assigneeIndex];
+      if (!submission || !("approved" in submission.status)) {
+        return false;
+      }
+
       // Check if volunteer has already claimed
       const alreadyClaimed = taskInfo.claimedAssignees.some(
         (claimedAssignee) =>
@@ -94,4 +94,175 @@ pub enum AltruistError {
 
     #[msg("Assignee has already claimed their reward")]
     AlreadyClaimed,
-}
\ No newline at end of file
+
+    #[msg("Unauthorized: Only the faucet authority can perform this action")]
+    UnauthorizedFaucetAuthority,
+
+    #[msg("No pending faucet authority transfer")]
+    NoPendingFaucetAuthority,
+
+    #[msg("Signer is not the pending faucet authority")]
+    InvalidPendingFaucetAuthority,
+
+    #[msg("Invalid rate limit: must be greater than zero")]
+    InvalidRateLimit,
+
+    #[msg("Invalid cooldown period (must be between 0 and 30 days)")]
+    InvalidCooldownPeriod,
+
+    #[msg("Account is not a legacy user request record")]
+    InvalidLegacyUserRecord,
+
+    #[msg("Invalid request cap configuration")]
+    InvalidRequestCap,
+
+    #[msg("Lifetime request cap exceeded for this user")]
+    LifetimeCapExceeded,
+
+    #[msg("Request window cap exceeded. Please wait for the window to reset")]
+    WindowCapExceeded,
+
+    #[msg("This faucet requires an allowlist proof")]
+    AllowlistProofRequired,
+
+    #[msg("This faucet has no allowlist configured")]
+    AllowlistNotConfigured,
+
+    #[msg("Invalid allowlist Merkle proof")]
+    InvalidMerkleProof,
+
+    #[msg("Request exceeds the user's allowlist allocation")]
+    AllocationExceeded,
+
+    #[msg("This faucet has no voucher signer configured")]
+    VoucherSignerNotConfigured,
+
+    #[msg("Voucher has expired")]
+    VoucherExpired,
+
+    #[msg("Missing or invalid voucher signature")]
+    InvalidVoucherSignature,
+
+    #[msg("Faucet is paused")]
+    FaucetPaused,
+
+    #[msg("Minting would exceed the faucet's maximum supply")]
+    MaxSupplyExceeded,
+
+    #[msg("Maximum supply cannot be below the amount already minted")]
+    InvalidMaxSupply,
+
+    #[msg("Mint decimals exceed the supported maximum")]
+    InvalidDecimals,
+
+    #[msg("Invalid combination of mint extensions")]
+    InvalidMintExtensions,
+
+    #[msg("Transfer hook can only be invoked during a token transfer")]
+    NotTransferring,
+
+    #[msg("Transfers are restricted to registered volunteers and charities")]
+    UnregisteredTransferParticipant,
+
+    #[msg("Faucet does not mint non-transferable impact points")]
+    NotImpactPointsFaucet,
+
+    #[msg("Task rewards are not funded by this faucet")]
+    InvalidRewardSource,
+
+    #[msg("Mint does not match the task's reward mint")]
+    InvalidRewardMint,
+
+    #[msg("Token program does not match the task's reward token program")]
+    InvalidTokenProgram,
+
+    #[msg("Assignee shares must cover every assignee and sum to 10,000 basis points")]
+    InvalidAssigneeShares,
+
+    #[msg("Too many milestones (max 10 allowed)")]
+    TooManyMilestones,
+
+    #[msg("Milestone amounts cannot exceed the task reward")]
+    InvalidMilestoneAmount,
+
+    #[msg("Milestone not found")]
+    InvalidMilestone,
+
+    #[msg("Invalid milestone status for this operation")]
+    InvalidMilestoneStatus,
+
+    #[msg("Approved milestones must be claimed by every assignee first")]
+    MilestonePayoutPending,
+
+    #[msg("All milestones must be approved before completing the task")]
+    MilestonesNotApproved,
+
+    #[msg("Task deadline must be in the future")]
+    InvalidDeadline,
+
+    #[msg("Task deadline has passed")]
+    TaskDeadlinePassed,
+
+    #[msg("Task has not expired yet")]
+    TaskNotExpired,
+
+    #[msg("Invalid submission status for this operation")]
+    InvalidSubmissionStatus,
+
+    #[msg("Assignee's submission has not been approved")]
+    SubmissionNotApproved,
+
+    #[msg("Task has no arbiter")]
+    NoArbiter,
+
+    #[msg("Task is frozen by an open dispute")]
+    TaskDisputed,
+
+    #[msg("Task has no open dispute")]
+    NoActiveDispute,
+
+    #[msg("Only the task arbiter can perform this action")]
+    UnauthorizedArbiter,
+
+    #[msg("Dispute split exceeds the unreleased reward")]
+    InvalidDisputeSplit,
+
+    #[msg("Kill fee cannot exceed 10,000 basis points")]
+    InvalidKillFee,
+
+    #[msg("Assignee token accounts must be passed in assignee order")]
+    InvalidAssigneeTokenAccount,
+
+    #[msg("No pending assignment invitation for this user")]
+    NoPendingInvitation,
+
+    #[msg("Invalid application status for this operation")]
+    InvalidApplicationStatus,
+
+    #[msg("The task creator cannot be its own arbiter")]
+    InvalidArbiter,
+
+    #[msg("Submission URI too long (max 200 characters)")]
+    SubmissionUriTooLong,
+
+    #[msg("Task assignments must all use shares or all split equally")]
+    MixedShareModes,
+
+    #[msg("Task rewards cannot use mints with transfer fees, transfer hooks or a permanent delegate")]
+    UnsupportedRewardMint,
+
+    #[msg("Faucet account already uses the current layout")]
+    FaucetAlreadyMigrated,
+
+    #[msg("Migrate the legacy user request record before requesting tokens")]
+    LegacyUserRecordNotMigrated,
+
+    #[msg("This faucet only accepts signed vouchers")]
+    VoucherRequired,
+
+    #[msg("A faucet can be gated by an allowlist or by vouchers, not both")]
+    ConflictingFaucetGates,
+
+    #[msg("Assignees can't be removed from a task with a kill fee once work has started")]
+    AssigneeRemovalLocked,
+}
@@ -1,24 +1,40 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::sysvar::instructions::{self as instructions_sysvar, get_instruction_relative},
+    system_program::{create_account, CreateAccount},
+};
 use anchor_spl::{
-    associated_token::AssociatedToken,
-    token_interface::{self, Mint, MintTo, TokenAccount, TransferChecked, Burn},
-    token_2022::{close_account, CloseAccount},
+    associated_token::{self, get_associated_token_address_with_program_id, AssociatedToken},
+    token_interface::{self, Mint, MintTo, TokenAccount, Burn},
+    token_2022::{close_account, initialize_mint2, thaw_account, CloseAccount, InitializeMint2, ThawAccount},
 };
 
 use anchor_spl::{
     token_interface::{
-        token_metadata_initialize,
-        Token2022, TokenMetadataInitialize,
+        default_account_state_initialize, metadata_pointer_initialize,
+        mint_close_authority_initialize, non_transferable_mint_initialize,
+        permanent_delegate_initialize, token_metadata_initialize, transfer_hook_initialize,
+        spl_token_2022::{
+            extension::ExtensionType,
+            onchain::invoke_transfer_checked,
+            state::{AccountState, Mint as MintState},
+        },
+        DefaultAccountStateInitialize, MetadataPointerInitialize, MintCloseAuthorityInitialize,
+        NonTransferableMintInitialize, PermanentDelegateInitialize,
+        Token2022, TokenMetadataInitialize, TransferHookInitialize,
     },
 };
 
 use crate::{
-    utils::update_account_lamports_to_minimum_balance,
+    utils::{close_program_account, update_account_lamports_to_minimum_balance},
+    merkle,
+    voucher,
     state::*,
     errors::*,
 };
 
 /// Initialize the faucet system with Token 2022 mint and metadata
+#[allow(clippy::too_many_arguments)]
 pub fn initialize_faucet(
     ctx: Context<InitializeFaucet>,
     faucet_seed: String,
@@ -26,14 +42,41 @@ pub fn initialize_faucet(
     symbol: String,
     uri: String,
     initial_supply: u64,
+    rate_limit: Option<u64>,
+    cooldown_period: i64,
+    max_supply: Option<u64>,
+    decimals: u8,
+    extensions: MintExtensionOptions,
 ) -> Result<()> {
     // Validate input lengths
     require!(name.len() <= 32, AltruistError::DescriptionTooLong);
     require!(symbol.len() <= 10, AltruistError::DescriptionTooLong);
     require!(uri.len() <= 200, AltruistError::DescriptionTooLong);
     require!(faucet_seed.len() <= 32, AltruistError::DescriptionTooLong);
+    require!(decimals <= Faucet::MAX_DECIMALS, AltruistError::InvalidDecimals);
+
+    // Default to 1000 whole tokens per request
+    let rate_limit = match rate_limit {
+        Some(rate_limit) => rate_limit,
+        None => Faucet::default_rate_limit(decimals)?,
+    };
+    Faucet::validate_config(rate_limit, cooldown_period)?;
+    if let Some(max_supply) = max_supply {
+        require!(initial_supply <= max_supply, AltruistError::MaxSupplyExceeded);
+    }
+    // Non-transferable tokens can't leave the faucet account, so they are minted on request
+    require!(
+        !extensions.non_transferable || initial_supply == 0,
+        AltruistError::InvalidMintExtensions
+    );
+    // A transfer hook would never run for a non-transferable mint
+    require!(
+        !(extensions.non_transferable && extensions.transfer_hook),
+        AltruistError::InvalidMintExtensions
+    );
 
     // Store values we need before borrowing faucet mutably
+    let payer_key = ctx.accounts.payer.key();
     let faucet_key = ctx.accounts.faucet.key();
     let faucet_token_account_key = ctx.accounts.faucet_token_account.key();
     let mint_key = ctx.accounts.mint.key();
@@ -42,15 +85,112 @@ pub fn initialize_faucet(
     // Initialize faucet account
     let faucet = &mut ctx.accounts.faucet;
     faucet.mint = mint_key;
-    faucet.authority = faucet_key;
+    faucet.authority = payer_key;
     faucet.token_account = faucet_token_account_key;
-    faucet.rate_limit = 1000 * 10_u64.pow(6); // 1000 tokens with 6 decimals
-    faucet.cooldown_period = 86400; // 24 hours in seconds
+    faucet.rate_limit = rate_limit;
+    faucet.cooldown_period = cooldown_period;
+    faucet.lifetime_cap = None;
+    faucet.window_cap = None;
+    faucet.window_period = 0;
+    faucet.merkle_root = None;
+    faucet.voucher_signer = None;
+    faucet.paused = false;
+    faucet.max_supply = max_supply;
+    faucet.total_minted = initial_supply;
+    faucet.reserved_budget = 0;
+    faucet.non_transferable = extensions.non_transferable;
+    faucet.transfer_restricted = false;
+    faucet.pending_authority = None;
     faucet.bump = faucet_bump;
 
     let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
     let signer = &[&seeds[..]];
 
+    // Create the mint account with room for every requested extension
+    let mut extension_types = vec![ExtensionType::MetadataPointer, ExtensionType::MintCloseAuthority];
+    extension_types.extend(extensions.extension_types());
+    let mint_space = ExtensionType::try_calculate_account_len::<MintState>(&extension_types)?;
+    let mint_lamports = Rent::get()?.minimum_balance(mint_space);
+
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        mint_lamports,
+        mint_space as u64,
+        ctx.accounts.token_program.key,
+    )?;
+
+    // Extensions must be initialized before the mint itself
+    let token_program_id = ctx.accounts.token_program.to_account_info();
+    let mint_info = ctx.accounts.mint.to_account_info();
+
+    metadata_pointer_initialize(
+        CpiContext::new(token_program_id.clone(), MetadataPointerInitialize {
+            token_program_id: token_program_id.clone(),
+            mint: mint_info.clone(),
+        }),
+        Some(faucet_key),
+        Some(mint_key),
+    )?;
+    mint_close_authority_initialize(
+        CpiContext::new(token_program_id.clone(), MintCloseAuthorityInitialize {
+            token_program_id: token_program_id.clone(),
+            mint: mint_info.clone(),
+        }),
+        Some(&faucet_key),
+    )?;
+    if let Some(permanent_delegate) = extensions.permanent_delegate {
+        permanent_delegate_initialize(
+            CpiContext::new(token_program_id.clone(), PermanentDelegateInitialize {
+                token_program_id: token_program_id.clone(),
+                mint: mint_info.clone(),
+            }),
+            &permanent_delegate,
+        )?;
+    }
+    if extensions.default_frozen {
+        default_account_state_initialize(
+            CpiContext::new(token_program_id.clone(), DefaultAccountStateInitialize {
+                token_program_id: token_program_id.clone(),
+                mint: mint_info.clone(),
+            }),
+            &AccountState::Frozen,
+        )?;
+    }
+    if extensions.non_transferable {
+        non_transferable_mint_initialize(
+            CpiContext::new(token_program_id.clone(), NonTransferableMintInitialize {
+                token_program_id: token_program_id.clone(),
+                mint: mint_info.clone(),
+            }),
+        )?;
+    }
+
+    if extensions.transfer_hook {
+        transfer_hook_initialize(
+            CpiContext::new(token_program_id.clone(), TransferHookInitialize {
+                token_program_id: token_program_id.clone(),
+                mint: mint_info.clone(),
+            }),
+            Some(faucet_key),
+            Some(crate::ID),
+        )?;
+    }
+
+    // Frozen-by-default mints need the faucet as freeze authority to thaw accounts
+    let freeze_authority = extensions.default_frozen.then_some(&faucet_key);
+    initialize_mint2(
+        CpiContext::new(token_program_id.clone(), InitializeMint2 { mint: mint_info.clone() }),
+        decimals,
+        &faucet_key,
+        freeze_authority,
+    )?;
+
     let cpi_accounts = TokenMetadataInitialize {
         program_id: ctx.accounts.token_program.to_account_info(),
         mint: ctx.accounts.mint.to_account_info(),
@@ -64,9 +204,8 @@ pub fn initialize_faucet(
         signer
     );
     token_metadata_initialize(cpi_ctx, name.clone(), symbol.clone(), uri.clone())?;
-    
-    ctx.accounts.mint.reload()?;
-    msg!("📋 Mint decimals: {}", ctx.accounts.mint.decimals);
+
+    msg!("📋 Mint decimals: {}", decimals);
 
     update_account_lamports_to_minimum_balance(
         ctx.accounts.mint.to_account_info(),
@@ -74,6 +213,29 @@ pub fn initialize_faucet(
         ctx.accounts.system_program.to_account_info(),
     )?;
 
+    // Create the faucet token account now that the mint exists
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        associated_token::Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.faucet_token_account.to_account_info(),
+            authority: ctx.accounts.faucet.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    if extensions.default_frozen {
+        let cpi_accounts = ThawAccount {
+            account: ctx.accounts.faucet_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            authority: ctx.accounts.faucet.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        thaw_account(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer))?;
+    }
+
     // Mint initial supply to faucet
     if initial_supply > 0 {
         let cpi_accounts = MintTo {
@@ -88,24 +250,156 @@ pub fn initialize_faucet(
     }
 
     msg!("Faucet initialized with mint: {}", mint_key);
-    msg!("Initial supply: {} tokens", initial_supply);
+    msg!("Initial supply: {} tokens (max supply: {:?})", initial_supply, max_supply);
+    msg!("Rate limit: {} tokens, cooldown: {} seconds", rate_limit, cooldown_period);
+    msg!("Mint extensions: {:?}", extensions);
 
     Ok(())
 }
 
 /// Allow users to request tokens from the faucet with rate limiting
-pub fn request_tokens(ctx: Context<RequestTokens>, faucet_seed: String, amount: u64) -> Result<()> {
-    let faucet = &ctx.accounts.faucet;
-    let user_record = &mut ctx.accounts.user_request_record;
+pub fn request_tokens<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestTokens<'info>>,
+    faucet_seed: String,
+    amount: u64,
+) -> Result<()> {
+    // Allowlisted faucets only accept requests that carry a Merkle proof
+    require!(
+        ctx.accounts.faucet.merkle_root.is_none(),
+        AltruistError::AllowlistProofRequired
+    );
+    // Voucher-gated faucets only accept signed vouchers
+    require!(
+        ctx.accounts.faucet.voucher_signer.is_none(),
+        AltruistError::VoucherRequired
+    );
+
+    distribute_tokens(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        ctx.bumps.user_request_record,
+        &faucet_seed,
+        amount,
+    )
+}
+
+/// Request tokens from an allowlisted faucet by proving membership in its Merkle tree
+pub fn request_tokens_with_proof<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestTokens<'info>>,
+    faucet_seed: String,
+    amount: u64,
+    allocation: Option<u64>,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let merkle_root = ctx.accounts.faucet.merkle_root
+        .ok_or(AltruistError::AllowlistNotConfigured)?;
+    require!(
+        ctx.accounts.faucet.voucher_signer.is_none(),
+        AltruistError::VoucherRequired
+    );
+
+    // Verify the user (and their allocation, if the tree includes one) is on the allowlist
+    let leaf = merkle::leaf_hash(&ctx.accounts.user.key(), allocation);
+    require!(
+        merkle::verify_proof(&proof, &merkle_root, &leaf),
+        AltruistError::InvalidMerkleProof
+    );
+
+    // Allocations cap the user's total from this faucet
+    if let Some(allocation) = allocation {
+        let total_received = ctx.accounts.user_request_record.total_received.checked_add(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        require!(total_received <= allocation, AltruistError::AllocationExceeded);
+    }
+
+    distribute_tokens(
+        ctx.accounts,
+        ctx.remaining_accounts,
+        ctx.bumps.user_request_record,
+        &faucet_seed,
+        amount,
+    )
+}
+
+/// Redeem a voucher signed off-chain by the faucet's voucher signer. The transaction must
+/// include an Ed25519 precompile instruction for the voucher immediately before this one.
+pub fn request_tokens_with_voucher<'info>(
+    ctx: Context<'_, '_, '_, 'info, RequestTokensWithVoucher<'info>>,
+    faucet_seed: String,
+    amount: u64,
+    expiry: i64,
+    nonce: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let faucet_key = ctx.accounts.request.faucet.key();
+    let user_key = ctx.accounts.request.user.key();
+
+    let voucher_signer = ctx.accounts.request.faucet.voucher_signer
+        .ok_or(AltruistError::VoucherSignerNotConfigured)?;
+    // Vouchers don't bypass an allowlist; the two gates are mutually exclusive
+    require!(
+        ctx.accounts.request.faucet.merkle_root.is_none(),
+        AltruistError::ConflictingFaucetGates
+    );
+    require!(clock.unix_timestamp < expiry, AltruistError::VoucherExpired);
+
+    // Verify the signature via the preceding Ed25519 precompile instruction
+    let ed25519_ix = get_instruction_relative(-1, &ctx.accounts.instructions_sysvar)
+        .map_err(|_| AltruistError::InvalidVoucherSignature)?;
+    let message = voucher::voucher_message(&faucet_key, &user_key, amount, expiry, nonce);
+    voucher::verify_ed25519_instruction(&ed25519_ix, &voucher_signer, &message)?;
+
+    // Record the nonce; the record's `init` constraint rejects replays
+    let voucher_record = &mut ctx.accounts.voucher_record;
+    voucher_record.faucet = faucet_key;
+    voucher_record.user = user_key;
+    voucher_record.nonce = nonce;
+    voucher_record.amount = amount;
+    voucher_record.redeemed_at = clock.unix_timestamp;
+    voucher_record.bump = ctx.bumps.voucher_record;
+
+    distribute_tokens(
+        &mut ctx.accounts.request,
+        ctx.remaining_accounts,
+        ctx.bumps.request.user_request_record,
+        &faucet_seed,
+        amount,
+    )
+}
+
+/// Apply rate limits, transfer tokens to the user and update their request record.
+/// `remaining_accounts` carries the extra accounts required by a transfer-hook mint.
+fn distribute_tokens<'info>(
+    accounts: &mut RequestTokens<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    user_record_bump: u8,
+    faucet_seed: &str,
+    amount: u64,
+) -> Result<()> {
+    let faucet = &accounts.faucet;
+    let user_record = &mut accounts.user_request_record;
     let clock = Clock::get()?;
 
+    require!(!faucet.paused, AltruistError::FaucetPaused);
+
+    // A leftover legacy record would let the user restart their history on this faucet
+    require!(
+        accounts.legacy_user_request_record.data_is_empty(),
+        AltruistError::LegacyUserRecordNotMigrated
+    );
+
     // Validate request amount
     require!(amount > 0, AltruistError::InvalidRewardAmount);
     require!(amount <= faucet.rate_limit, AltruistError::RequestAmountTooHigh);
 
-    // Check faucet balance
-    let faucet_balance = ctx.accounts.faucet_token_account.amount;
-    require!(faucet_balance >= amount, AltruistError::InsufficientFaucetBalance);
+    // Check faucet balance (non-transferable faucets mint on request instead)
+    let total_minted = if faucet.non_transferable {
+        faucet.checked_mint_total(amount)?
+    } else {
+        let faucet_balance = accounts.faucet_token_account.amount;
+        require!(faucet_balance >= amount, AltruistError::InsufficientFaucetBalance);
+        faucet.total_minted
+    };
 
     // Check rate limiting
     if user_record.last_request > 0 {
@@ -116,31 +410,97 @@ pub fn request_tokens(ctx: Context<RequestTokens>, faucet_seed: String, amount:
         );
     }
 
-    // Transfer tokens from faucet to user
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.faucet_token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.user_token_account.to_account_info(),
-        authority: ctx.accounts.faucet.to_account_info(),
-    };
+    // Check per-user lifetime cap
+    let total_received = user_record.total_received.checked_add(amount)
+        .ok_or(AltruistError::ArithmeticOverflow)?;
+    if let Some(lifetime_cap) = faucet.lifetime_cap {
+        require!(total_received <= lifetime_cap, AltruistError::LifetimeCapExceeded);
+    }
+
+    // Check per-user window cap
+    let (window_start, window_received) =
+        user_record.current_window(faucet.window_period, clock.unix_timestamp);
+    let window_received = window_received.checked_add(amount)
+        .ok_or(AltruistError::ArithmeticOverflow)?;
+    if let Some(window_cap) = faucet.window_cap {
+        require!(window_received <= window_cap, AltruistError::WindowCapExceeded);
+    }
+
     let faucet_bump = faucet.bump;
     let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
     let signer = &[&seeds[..]];
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
-    
-    token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+    let cpi_program = accounts.token_program.to_account_info();
+
+    if faucet.non_transferable {
+        // Mint tokens directly to user
+        let cpi_accounts = MintTo {
+            mint: accounts.mint.to_account_info(),
+            to: accounts.user_token_account.to_account_info(),
+            authority: accounts.faucet.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token_interface::mint_to(cpi_ctx, amount)?;
+    } else {
+        // Transfer tokens from faucet to user, resolving any transfer hook accounts
+        invoke_transfer_checked(
+            cpi_program.key,
+            accounts.faucet_token_account.to_account_info(),
+            accounts.mint.to_account_info(),
+            accounts.user_token_account.to_account_info(),
+            accounts.faucet.to_account_info(),
+            remaining_accounts,
+            amount,
+            accounts.mint.decimals,
+            signer,
+        )?;
+    }
+    accounts.faucet.total_minted = total_minted;
 
     // Update user request record
-    user_record.user = ctx.accounts.user.key();
+    user_record.user = accounts.user.key();
+    user_record.faucet = accounts.faucet.key();
     user_record.last_request = clock.unix_timestamp;
-    user_record.total_received = user_record.total_received.checked_add(amount)
-        .ok_or(AltruistError::ArithmeticOverflow)?;
+    user_record.total_received = total_received;
     user_record.request_count = user_record.request_count.checked_add(1)
         .ok_or(AltruistError::ArithmeticOverflow)?;
+    user_record.window_start = window_start;
+    user_record.window_received = window_received;
+    user_record.bump = user_record_bump;
+
+    msg!("User {} received {} tokens", accounts.user.key(), amount);
+
+    Ok(())
+}
+
+/// Move a legacy user request record (seeded by user only) to the per-faucet layout,
+/// carrying over its history and returning the old account's rent to the user
+pub fn migrate_user_request_record(
+    ctx: Context<MigrateUserRequestRecord>,
+    _faucet_seed: String,
+) -> Result<()> {
+    let legacy_info = ctx.accounts.legacy_user_request_record.to_account_info();
+
+    let legacy_record =
+        LegacyUserRequestRecord::try_from_account_data(&legacy_info.try_borrow_data()?)?;
+    require!(
+        legacy_record.user == ctx.accounts.user.key(),
+        AltruistError::InvalidLegacyUserRecord
+    );
+
+    let user_record = &mut ctx.accounts.user_request_record;
+    user_record.user = legacy_record.user;
+    user_record.faucet = ctx.accounts.faucet.key();
+    user_record.last_request = legacy_record.last_request;
+    user_record.total_received = legacy_record.total_received;
+    user_record.request_count = legacy_record.request_count;
+    user_record.window_start = 0;
+    user_record.window_received = 0;
     user_record.bump = ctx.bumps.user_request_record;
 
-    msg!("User {} received {} tokens", ctx.accounts.user.key(), amount);
+    close_program_account(legacy_info, ctx.accounts.user.to_account_info())?;
+
+    msg!("User record for {} migrated to faucet {}", legacy_record.user, user_record.faucet);
 
     Ok(())
 }
@@ -158,10 +518,10 @@ pub fn delete_faucet(ctx: Context<DeleteFaucet>, faucet_seed: String) -> Result<
     let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
     let signer = &[&seeds[..]];
 
-    // Close the faucet token account first (returns rent to payer)
+    // Close the faucet token account first (returns rent to authority)
     let close_token_account_cpi = anchor_spl::token_interface::CloseAccount {
         account: ctx.accounts.faucet_token_account.to_account_info(),
-        destination: ctx.accounts.payer.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
         authority: ctx.accounts.faucet.to_account_info(),
     };
     let cpi_ctx = CpiContext::new_with_signer(
@@ -178,7 +538,7 @@ pub fn delete_faucet(ctx: Context<DeleteFaucet>, faucet_seed: String) -> Result<
         ctx.accounts.token_program.to_account_info(),
         CloseAccount {
             account: ctx.accounts.mint.to_account_info(),
-            destination: ctx.accounts.payer.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
             authority: ctx.accounts.faucet.to_account_info(),
         },
         signer
@@ -219,10 +579,10 @@ pub fn burn_and_delete_faucet(ctx: Context<BurnAndDeleteFaucet>, faucet_seed: St
         msg!("Successfully burned {} tokens", faucet_balance);
     }
 
-    // Close the faucet token account (returns rent to payer)
+    // Close the faucet token account (returns rent to authority)
     let close_token_account_cpi = anchor_spl::token_interface::CloseAccount {
         account: ctx.accounts.faucet_token_account.to_account_info(),
-        destination: ctx.accounts.payer.to_account_info(),
+        destination: ctx.accounts.authority.to_account_info(),
         authority: ctx.accounts.faucet.to_account_info(),
     };
     let cpi_ctx = CpiContext::new_with_signer(
@@ -239,7 +599,7 @@ pub fn burn_and_delete_faucet(ctx: Context<BurnAndDeleteFaucet>, faucet_seed: St
         ctx.accounts.token_program.to_account_info(),
         CloseAccount {
             account: ctx.accounts.mint.to_account_info(),
-            destination: ctx.accounts.payer.to_account_info(),
+            destination: ctx.accounts.authority.to_account_info(),
             authority: ctx.accounts.faucet.to_account_info(),
         },
         signer
@@ -265,26 +625,20 @@ pub struct InitializeFaucet<'info> {
     )]
     pub faucet: Account<'info, Faucet>,
 
-    #[account(
-        init,
-        payer = payer,
-        mint::decimals = 6,
-        mint::authority = faucet,
-        mint::token_program = token_program,
-        extensions::metadata_pointer::authority = faucet,
-        extensions::metadata_pointer::metadata_address = mint,
-        extensions::close_authority::authority = faucet,
-    )]
-    pub mint: Box<InterfaceAccount<'info, Mint>>,
+    /// New mint keypair; the mint is created with the requested extensions in the handler
+    #[account(mut)]
+    pub mint: Signer<'info>,
 
+    /// CHECK: Faucet's associated token account, created in the handler once the mint exists
     #[account(
-        init,
-        payer = payer,
-        associated_token::mint = mint,
-        associated_token::authority = faucet,
-        associated_token::token_program = token_program,
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &faucet.key(),
+            &mint.key(),
+            &token_program.key(),
+        ),
     )]
-    pub faucet_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub faucet_token_account: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -298,6 +652,7 @@ pub struct InitializeFaucet<'info> {
 #[instruction(faucet_seed: String)]
 pub struct RequestTokens<'info> {
     #[account(
+        mut,
         seeds = [faucet_seed.as_bytes()],
         bump = faucet.bump
     )]
@@ -315,11 +670,18 @@ pub struct RequestTokens<'info> {
         init_if_needed,
         payer = user,
         space = UserRequestRecord::LEN,
-        seeds = [b"user_record", user.key().as_ref()],
+        seeds = [b"user_record", faucet.key().as_ref(), user.key().as_ref()],
         bump
     )]
     pub user_request_record: Account<'info, UserRequestRecord>,
 
+    /// CHECK: Pre-migration user record; must be empty, i.e. migrated or never created
+    #[account(
+        seeds = [b"user_record", user.key().as_ref()],
+        bump,
+    )]
+    pub legacy_user_request_record: UncheckedAccount<'info>,
+
     #[account(
         init_if_needed,
         payer = user,
@@ -330,6 +692,7 @@ pub struct RequestTokens<'info> {
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     #[account(
+        mut,
         address = faucet.mint
     )]
     pub mint: InterfaceAccount<'info, Mint>,
@@ -342,6 +705,60 @@ pub struct RequestTokens<'info> {
     pub system_program: Program<'info, System>,
 }
 
+#[derive(Accounts)]
+#[instruction(faucet_seed: String, amount: u64, expiry: i64, nonce: u64)]
+pub struct RequestTokensWithVoucher<'info> {
+    pub request: RequestTokens<'info>,
+
+    #[account(
+        init,
+        payer = request.user,
+        space = VoucherRecord::LEN,
+        seeds = [b"voucher", request.faucet.key().as_ref(), nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub voucher_record: Account<'info, VoucherRecord>,
+
+    /// CHECK: Instructions sysvar, used to read the Ed25519 verification instruction
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct MigrateUserRequestRecord<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    /// CHECK: Record in the pre-migration layout; validated and closed in the handler
+    #[account(
+        mut,
+        seeds = [b"user_record", user.key().as_ref()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub legacy_user_request_record: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = UserRequestRecord::LEN,
+        seeds = [b"user_record", faucet.key().as_ref(), user.key().as_ref()],
+        bump
+    )]
+    pub user_request_record: Account<'info, UserRequestRecord>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
 #[derive(Accounts)]
 #[instruction(faucet_seed: String)]
 pub struct DeleteFaucet<'info> {
@@ -349,7 +766,8 @@ pub struct DeleteFaucet<'info> {
         mut,
         seeds = [faucet_seed.as_bytes()],
         bump = faucet.bump,
-        close = payer
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+        close = authority
     )]
     pub faucet: Account<'info, Faucet>,
 
@@ -369,7 +787,7 @@ pub struct DeleteFaucet<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -383,7 +801,8 @@ pub struct BurnAndDeleteFaucet<'info> {
         mut,
         seeds = [faucet_seed.as_bytes()],
         bump = faucet.bump,
-        close = payer
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+        close = authority
     )]
     pub faucet: Account<'info, Faucet>,
 
@@ -403,9 +822,9 @@ pub struct BurnAndDeleteFaucet<'info> {
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token2022>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-}
\ No newline at end of file
+}
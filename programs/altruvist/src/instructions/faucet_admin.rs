@@ -0,0 +1,555 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::program::invoke_signed,
+};
+use anchor_spl::{
+    token_2022::{thaw_account, ThawAccount},
+    token_2022_extensions::spl_token_metadata_interface::{self, state::Field},
+    token_interface::{
+        self, token_metadata_update_field, Mint, MintTo, Token2022, TokenAccount,
+        TokenMetadataUpdateField,
+    },
+};
+
+use crate::{
+    utils::update_account_lamports_to_minimum_balance,
+    state::*,
+    errors::*,
+};
+
+/// Propose a new faucet authority (first step of a two-step transfer)
+pub fn propose_faucet_authority(
+    ctx: Context<ProposeFaucetAuthority>,
+    _faucet_seed: String,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+
+    // Proposing again simply replaces any earlier pending proposal
+    faucet.pending_authority = Some(new_authority);
+
+    msg!("Faucet authority transfer proposed: {} -> {}", faucet.authority, new_authority);
+
+    Ok(())
+}
+
+/// Accept a pending faucet authority transfer (second step, signed by the new authority)
+pub fn accept_faucet_authority(
+    ctx: Context<AcceptFaucetAuthority>,
+    _faucet_seed: String,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+    let new_authority = ctx.accounts.new_authority.key();
+
+    let pending_authority = faucet.pending_authority
+        .ok_or(AltruistError::NoPendingFaucetAuthority)?;
+    require!(
+        pending_authority == new_authority,
+        AltruistError::InvalidPendingFaucetAuthority
+    );
+
+    let previous_authority = faucet.authority;
+    faucet.authority = new_authority;
+    faucet.pending_authority = None;
+
+    msg!("Faucet authority transferred: {} -> {}", previous_authority, new_authority);
+
+    Ok(())
+}
+
+/// Update the faucet rate limit and/or cooldown period
+pub fn update_faucet_config(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    rate_limit: Option<u64>,
+    cooldown_period: Option<i64>,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+
+    // Unspecified values keep their current setting
+    let new_rate_limit = rate_limit.unwrap_or(faucet.rate_limit);
+    let new_cooldown_period = cooldown_period.unwrap_or(faucet.cooldown_period);
+    Faucet::validate_config(new_rate_limit, new_cooldown_period)?;
+
+    faucet.rate_limit = new_rate_limit;
+    faucet.cooldown_period = new_cooldown_period;
+
+    msg!("Faucet config updated: rate limit {} tokens, cooldown {} seconds",
+         new_rate_limit, new_cooldown_period);
+
+    Ok(())
+}
+
+/// Set the per-user lifetime cap and window cap (`None` disables a cap)
+pub fn set_faucet_request_caps(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    lifetime_cap: Option<u64>,
+    window_cap: Option<u64>,
+    window_period: i64,
+) -> Result<()> {
+    Faucet::validate_caps(lifetime_cap, window_cap, window_period)?;
+
+    let faucet = &mut ctx.accounts.faucet;
+    faucet.lifetime_cap = lifetime_cap;
+    faucet.window_cap = window_cap;
+    faucet.window_period = window_period;
+
+    msg!("Faucet request caps updated: lifetime {:?}, window {:?} per {} seconds",
+         lifetime_cap, window_cap, window_period);
+
+    Ok(())
+}
+
+/// Set or clear the allowlist Merkle root. Allowlists and vouchers are mutually exclusive.
+pub fn set_faucet_merkle_root(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    merkle_root: Option<[u8; 32]>,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+    require!(
+        merkle_root.is_none() || faucet.voucher_signer.is_none(),
+        AltruistError::ConflictingFaucetGates
+    );
+    faucet.merkle_root = merkle_root;
+
+    if merkle_root.is_some() {
+        msg!("Faucet allowlist enabled");
+    } else {
+        msg!("Faucet allowlist disabled");
+    }
+
+    Ok(())
+}
+
+/// Set or clear the key trusted to sign faucet vouchers. Vouchers and allowlists are
+/// mutually exclusive; once set, vouchers are the only way to request tokens.
+pub fn set_faucet_voucher_signer(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    voucher_signer: Option<Pubkey>,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+    require!(
+        voucher_signer.is_none() || faucet.merkle_root.is_none(),
+        AltruistError::ConflictingFaucetGates
+    );
+    faucet.voucher_signer = voucher_signer;
+
+    msg!("Faucet voucher signer set to {:?}", voucher_signer);
+
+    Ok(())
+}
+
+/// Enable or disable the transfer hook's registered-participant rule
+pub fn set_faucet_transfer_restricted(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    transfer_restricted: bool,
+) -> Result<()> {
+    ctx.accounts.faucet.transfer_restricted = transfer_restricted;
+    msg!("Faucet transfer restriction set to {}", transfer_restricted);
+    Ok(())
+}
+
+/// Halt all token requests without closing the faucet
+pub fn pause_faucet(ctx: Context<UpdateFaucetConfig>, _faucet_seed: String) -> Result<()> {
+    ctx.accounts.faucet.paused = true;
+    msg!("Faucet paused");
+    Ok(())
+}
+
+/// Resume token requests
+pub fn unpause_faucet(ctx: Context<UpdateFaucetConfig>, _faucet_seed: String) -> Result<()> {
+    ctx.accounts.faucet.paused = false;
+    msg!("Faucet unpaused");
+    Ok(())
+}
+
+/// Mint additional tokens into the faucet token account, bounded by the supply cap
+pub fn mint_to_faucet(
+    ctx: Context<MintToFaucet>,
+    faucet_seed: String,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, AltruistError::InvalidRewardAmount);
+    // Non-transferable tokens could never leave the faucet account, so they are minted on request
+    require!(!ctx.accounts.faucet.non_transferable, AltruistError::InvalidMintExtensions);
+
+    let total_minted = ctx.accounts.faucet.checked_mint_total(amount)?;
+
+    let faucet_bump = ctx.accounts.faucet.bump;
+    let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.faucet_token_account.to_account_info(),
+        authority: ctx.accounts.faucet.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+    token_interface::mint_to(cpi_ctx, amount)?;
+
+    let faucet = &mut ctx.accounts.faucet;
+    faucet.total_minted = total_minted;
+
+    msg!("Minted {} tokens to faucet (total minted: {}, max supply: {:?})",
+         amount, total_minted, faucet.max_supply);
+
+    Ok(())
+}
+
+/// Thaw a token account of a default-frozen faucet mint so it can receive tokens
+pub fn thaw_faucet_token_account(
+    ctx: Context<ThawFaucetTokenAccount>,
+    faucet_seed: String,
+) -> Result<()> {
+    let faucet_bump = ctx.accounts.faucet.bump;
+    let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = ThawAccount {
+        account: ctx.accounts.token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        authority: ctx.accounts.faucet.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+    thaw_account(cpi_ctx)?;
+
+    msg!("Token account thawed: {}", ctx.accounts.token_account.key());
+
+    Ok(())
+}
+
+/// Set or remove the faucet's maximum supply
+pub fn set_faucet_max_supply(
+    ctx: Context<UpdateFaucetConfig>,
+    _faucet_seed: String,
+    max_supply: Option<u64>,
+) -> Result<()> {
+    let faucet = &mut ctx.accounts.faucet;
+
+    if let Some(max_supply) = max_supply {
+        let committed = faucet.total_minted.checked_add(faucet.reserved_budget)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        require!(max_supply >= committed, AltruistError::InvalidMaxSupply);
+    }
+    faucet.max_supply = max_supply;
+
+    msg!("Faucet max supply set to {:?}", max_supply);
+
+    Ok(())
+}
+
+/// Update the name, symbol, uri or an additional key-value field of the faucet mint metadata
+pub fn update_faucet_metadata(
+    ctx: Context<UpdateFaucetMetadata>,
+    faucet_seed: String,
+    field: FaucetMetadataField,
+    value: String,
+) -> Result<()> {
+    // Same limits as initialize_faucet for the standard fields
+    let field = match field {
+        FaucetMetadataField::Name => {
+            require!(value.len() <= 32, AltruistError::DescriptionTooLong);
+            Field::Name
+        }
+        FaucetMetadataField::Symbol => {
+            require!(value.len() <= 10, AltruistError::DescriptionTooLong);
+            Field::Symbol
+        }
+        FaucetMetadataField::Uri => {
+            require!(value.len() <= 200, AltruistError::DescriptionTooLong);
+            Field::Uri
+        }
+        FaucetMetadataField::Key(key) => {
+            require!(!key.is_empty() && key.len() <= 32, AltruistError::DescriptionTooLong);
+            require!(value.len() <= 200, AltruistError::DescriptionTooLong);
+            Field::Key(key)
+        }
+    };
+
+    let faucet_bump = ctx.accounts.faucet.bump;
+    let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TokenMetadataUpdateField {
+        program_id: ctx.accounts.token_program.to_account_info(),
+        metadata: ctx.accounts.mint.to_account_info(),
+        update_authority: ctx.accounts.faucet.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer
+    );
+    token_metadata_update_field(cpi_ctx, field.clone(), value.clone())?;
+
+    // The metadata may have grown, so top up rent from the authority
+    update_account_lamports_to_minimum_balance(
+        ctx.accounts.mint.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    msg!("Faucet metadata {:?} updated to: {}", field, value);
+
+    Ok(())
+}
+
+/// Remove an additional key-value field from the faucet mint metadata
+pub fn remove_faucet_metadata_key(
+    ctx: Context<UpdateFaucetMetadata>,
+    faucet_seed: String,
+    key: String,
+) -> Result<()> {
+    let faucet_bump = ctx.accounts.faucet.bump;
+    let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
+    let signer = &[&seeds[..]];
+
+    let ix = spl_token_metadata_interface::instruction::remove_key(
+        ctx.accounts.token_program.key,
+        &ctx.accounts.mint.key(),
+        &ctx.accounts.faucet.key(),
+        key.clone(),
+        false,
+    );
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.mint.to_account_info(),
+            ctx.accounts.faucet.to_account_info(),
+        ],
+        signer,
+    )?;
+
+    msg!("Faucet metadata key removed: {}", key);
+
+    Ok(())
+}
+
+/// Migrate a faucet created with the legacy layout to the current one. Legacy faucets
+/// had no admin, so the program's upgrade authority migrates them and becomes the admin.
+pub fn migrate_faucet(
+    ctx: Context<MigrateFaucet>,
+    _faucet_seed: String,
+) -> Result<()> {
+    let faucet_info = ctx.accounts.faucet.to_account_info();
+
+    // Read the legacy fields before the account is resized
+    let (mint, token_account, rate_limit, cooldown_period, bump) = {
+        let data = faucet_info.try_borrow_data()?;
+        require!(
+            data.len() == Faucet::LEGACY_LEN && data[..8] == *Faucet::DISCRIMINATOR,
+            AltruistError::FaucetAlreadyMigrated
+        );
+        let read_u64 = |offset: usize| -> [u8; 8] {
+            data[offset..offset + 8].try_into().unwrap()
+        };
+        (
+            Pubkey::try_from(&data[8..40]).unwrap(),
+            Pubkey::try_from(&data[72..104]).unwrap(),
+            u64::from_le_bytes(read_u64(104)),
+            i64::from_le_bytes(read_u64(112)),
+            data[120],
+        )
+    };
+    require!(ctx.accounts.mint.key() == mint, AltruistError::InvalidRewardMint);
+
+    let faucet = Faucet {
+        mint,
+        authority: ctx.accounts.authority.key(),
+        token_account,
+        rate_limit,
+        cooldown_period,
+        lifetime_cap: None,
+        window_cap: None,
+        window_period: 0,
+        merkle_root: None,
+        voucher_signer: None,
+        paused: false,
+        max_supply: None,
+        // Every token in circulation was minted by the faucet
+        total_minted: ctx.accounts.mint.supply,
+        reserved_budget: 0,
+        non_transferable: false,
+        transfer_restricted: false,
+        pending_authority: None,
+        bump,
+    };
+
+    faucet_info.resize(Faucet::LEN)?;
+    update_account_lamports_to_minimum_balance(
+        faucet_info.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+    )?;
+    let mut data = faucet_info.try_borrow_mut_data()?;
+    faucet.try_serialize(&mut &mut data[..])?;
+
+    msg!("Faucet for mint {} migrated, authority: {}", mint, faucet.authority);
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct ProposeFaucetAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct AcceptFaucetAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    pub new_authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct UpdateFaucetConfig<'info> {
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct MintToFaucet<'info> {
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = faucet,
+        associated_token::token_program = token_program,
+    )]
+    pub faucet_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = faucet.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct ThawFaucetTokenAccount<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = faucet.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct UpdateFaucetMetadata<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        address = faucet.mint,
+        extensions::metadata_pointer::metadata_address = mint,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct MigrateFaucet<'info> {
+    /// CHECK: Legacy faucet that can't be deserialized as `Faucet`; its owner, size and
+    /// discriminator are checked in the handler
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump,
+        owner = crate::ID,
+    )]
+    pub faucet: UncheckedAccount<'info>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Program upgrade authority, which becomes the faucet admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub program: Program<'info, crate::program::Altruvist>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key()) @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
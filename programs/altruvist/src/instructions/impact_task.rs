@@ -0,0 +1,386 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, MintTo, TokenAccount},
+    token_interface::Token2022,
+};
+
+use crate::{
+    state::*,
+    errors::*,
+};
+
+/// Create a task whose reward is minted as impact points from a non-transferable faucet.
+/// The reward is reserved from the faucet's supply instead of being moved into escrow.
+pub fn create_impact_task(
+    ctx: Context<CreateImpactTask>,
+    task_id: String,
+    _faucet_seed: String,
+    reward_amount: u64,
+    deadline: Option<i64>,
+    kill_fee_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate inputs
+    require!(task_id.len() <= 50, AltruistError::TaskIdTooLong);
+    require!(reward_amount > 0, AltruistError::InvalidRewardAmount);
+    if let Some(deadline) = deadline {
+        require!(deadline > clock.unix_timestamp, AltruistError::InvalidDeadline);
+    }
+    require!(kill_fee_bps <= Task::TOTAL_SHARE_BPS, AltruistError::InvalidKillFee);
+
+    // Validate task_id format (alphanumeric, underscore, hyphen only)
+    require!(
+        task_id.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-'),
+        AltruistError::InvalidTaskIdFormat
+    );
+
+    // Reserve the task budget against the faucet's max supply
+    ctx.accounts.faucet.reserve_budget(reward_amount)?;
+
+    // Initialize task account
+    let task = &mut ctx.accounts.task;
+    task.task_id = task_id.clone();
+    task.reward_amount = reward_amount;
+    task.status = TaskStatus::Created;
+    task.creator = ctx.accounts.creator.key();
+    task.escrow_account = Pubkey::default();
+    task.mint = ctx.accounts.faucet.mint;
+    task.token_program = Token2022::id();
+    task.assignees = Vec::new();
+    task.assignee_shares = Vec::new();
+    task.payable_amounts = Vec::new();
+    task.claimed_assignees = Vec::new();
+    task.milestones = Vec::new();
+    task.submissions = Vec::new();
+    task.invitations = Vec::new();
+    task.created_at = clock.unix_timestamp;
+    task.updated_at = clock.unix_timestamp;
+    task.pending_decrease_amount = None;
+    task.decrease_requested_at = None;
+    task.points_faucet = Some(ctx.accounts.faucet.key());
+    task.deadline = deadline;
+    task.arbiter = None;
+    task.disputed = false;
+    task.kill_fee_bps = kill_fee_bps;
+    task.bump = ctx.bumps.task;
+
+    msg!("Impact task created: {} with budget: {} points", task_id, reward_amount);
+
+    Ok(())
+}
+
+/// Claim an impact task reward, minting points directly to the assignee
+pub fn claim_impact_reward(
+    ctx: Context<ClaimImpactReward>,
+    _task_id: String,
+    faucet_seed: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
+
+    // Validate task status, assignment and that this assignee hasn't claimed yet
+    task.check_can_claim(&assignee_key)?;
+
+    let claim_amount = task.claim_amount(&assignee_key)?;
+
+    // Mint reward to the claiming assignee
+    let faucet_bump = ctx.accounts.faucet.bump;
+    let seeds = &[faucet_seed.as_bytes(), &[faucet_bump]];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.assignee_token_account.to_account_info(),
+        authority: ctx.accounts.faucet.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+    token_interface::mint_to(cpi_ctx, claim_amount)?;
+
+    ctx.accounts.faucet.mint_from_budget(claim_amount)?;
+
+    // Mark this assignee as having claimed their reward
+    let task = &mut ctx.accounts.task;
+    task.mark_claimed(assignee_key)?;
+
+    if task.all_rewards_claimed() {
+        msg!("All assignees have claimed their rewards - call close_task to close the account");
+    }
+
+    msg!("Impact reward of {} points minted to assignee {} ({}/{} assignees have claimed)",
+         claim_amount, assignee_key,
+         task.claimed_assignees.len(), task.assignees.len());
+
+    Ok(())
+}
+
+/// Cancel an impact task and release its unminted budget back to the faucet. Cancelling
+/// an `InProgress` task first mints the kill fee to the current assignees, whose token
+/// accounts are passed as remaining accounts in assignee order.
+pub fn delete_impact_task<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeleteImpactTask<'info>>,
+    task_id: String,
+    faucet_seed: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+
+    // Validate authority and task status
+    require!(
+        task.can_cancel(&ctx.accounts.creator.key()),
+        AltruistError::UnauthorizedTaskCreator
+    );
+
+    // Nothing has been minted before completion, so the budget left after the kill
+    // fee is released
+    let kill_fee = task.kill_fee()?;
+    let release_amount = task.reward_amount - kill_fee;
+    if kill_fee > 0 {
+        mint_kill_fee(
+            task,
+            kill_fee,
+            ctx.remaining_accounts,
+            &mut ctx.accounts.faucet,
+            &faucet_seed,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+        )?;
+    }
+    ctx.accounts.faucet.release_budget(release_amount)?;
+
+    let task = &mut ctx.accounts.task;
+    task.update_status(TaskStatus::Cancelled);
+
+    msg!("Impact task {} cancelled. {} points released to the faucet", task_id, release_amount);
+
+    Ok(())
+}
+
+/// Expire an impact task after its deadline, releasing its unminted budget back to the
+/// faucet. Permissionless so anyone can crank stale tasks. An `InProgress` task still
+/// mints its kill fee to the assignees, whose token accounts are passed as remaining
+/// accounts in assignee order.
+pub fn expire_impact_task<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExpireImpactTask<'info>>,
+    task_id: String,
+    faucet_seed: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(task.can_expire(clock.unix_timestamp), AltruistError::TaskNotExpired);
+    task.check_not_disputed()?;
+
+    // Letting the deadline pass doesn't avoid the kill fee
+    let kill_fee = task.kill_fee()?;
+    let release_amount = task.reward_amount - kill_fee;
+    if kill_fee > 0 {
+        mint_kill_fee(
+            task,
+            kill_fee,
+            ctx.remaining_accounts,
+            &mut ctx.accounts.faucet,
+            &faucet_seed,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+        )?;
+    }
+    ctx.accounts.faucet.release_budget(release_amount)?;
+
+    let task = &mut ctx.accounts.task;
+    task.update_status(TaskStatus::Cancelled);
+
+    msg!("Impact task {} expired by {}. {} points released to the faucet",
+         task_id, ctx.accounts.payer.key(), release_amount);
+
+    Ok(())
+}
+
+/// Mint `kill_fee` points from the task budget to the task's assignees, split by their
+/// shares. Their token accounts are passed as remaining accounts in assignee order.
+fn mint_kill_fee<'info>(
+    task: &Account<'info, Task>,
+    kill_fee: u64,
+    assignee_token_accounts: &'info [AccountInfo<'info>],
+    faucet: &mut Account<'info, Faucet>,
+    faucet_seed: &str,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Program<'info, Token2022>,
+) -> Result<()> {
+    task.validate_shares()?;
+    require!(
+        assignee_token_accounts.len() == task.assignees.len(),
+        AltruistError::InvalidAssigneeTokenAccount
+    );
+
+    let seeds = &[faucet_seed.as_bytes(), &[faucet.bump]];
+    let signer = &[&seeds[..]];
+
+    let payouts = task.split_amount(kill_fee)?;
+    for ((assignee, account_info), payout) in task.assignees.iter()
+        .zip(assignee_token_accounts.iter())
+        .zip(payouts)
+    {
+        let assignee_token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require!(
+            assignee_token_account.owner == *assignee && assignee_token_account.mint == task.mint,
+            AltruistError::InvalidAssigneeTokenAccount
+        );
+
+        let cpi_accounts = MintTo {
+            mint: mint.to_account_info(),
+            to: account_info.clone(),
+            authority: faucet.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+
+        token_interface::mint_to(cpi_ctx, payout)?;
+    }
+    faucet.mint_from_budget(kill_fee)?;
+
+    msg!("Kill fee of {} points minted to {} assignees", kill_fee, task.assignees.len());
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String, faucet_seed: String)]
+pub struct CreateImpactTask<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Task::LEN,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    pub task: Account<'info, Task>,
+
+    /// Only the faucet authority may commit the faucet's supply to task budgets
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        constraint = faucet.authority == creator.key() @ AltruistError::UnauthorizedFaucetAuthority,
+        constraint = faucet.non_transferable @ AltruistError::NotImpactPointsFaucet,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String, faucet_seed: String)]
+pub struct ClaimImpactReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        constraint = task.points_faucet == Some(faucet.key()) @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        init_if_needed,
+        payer = assignee,
+        associated_token::mint = mint,
+        associated_token::authority = assignee,
+        associated_token::token_program = token_program,
+    )]
+    pub assignee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub assignee: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String, faucet_seed: String)]
+pub struct DeleteImpactTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+        close = creator,  // Close task account and send lamports to creator
+        constraint = task.points_faucet == Some(faucet.key()) @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String, faucet_seed: String)]
+pub struct ExpireImpactTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        close = creator,  // Close task account and send lamports to creator
+        constraint = task.points_faucet == Some(faucet.key()) @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Receives the closed task account's rent
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ AltruistError::InvalidCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Anyone may expire a task once its deadline has passed
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token2022>,
+}
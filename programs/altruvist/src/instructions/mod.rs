@@ -1,8 +1,24 @@
 pub mod faucet;
+pub mod faucet_admin;
+pub mod impact_task;
+pub mod sol_task;
 pub mod task;
+pub mod task_application;
+pub mod task_dispute;
+pub mod task_milestone;
+pub mod task_submission;
 pub mod task_reward;
+pub mod transfer_hook;
 
 // Re-export instruction functions
 pub use faucet::*;
+pub use faucet_admin::*;
+pub use impact_task::*;
+pub use sol_task::*;
 pub use task::*;
-pub use task_reward::*;
\ No newline at end of file
+pub use task_application::*;
+pub use task_dispute::*;
+pub use task_milestone::*;
+pub use task_submission::*;
+pub use task_reward::*;
+pub use transfer_hook::*;
\ No newline at end of file
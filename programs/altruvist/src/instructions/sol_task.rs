@@ -0,0 +1,406 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+use anchor_spl::token::spl_token::native_mint;
+
+use crate::{
+    utils::{lamports_above_rent, transfer_program_lamports},
+    state::*,
+    errors::*,
+};
+
+/// Create a task whose reward is native SOL escrowed as lamports in the task account.
+/// Rent for the task account is paid separately and never counted as reward.
+pub fn create_sol_task(
+    ctx: Context<CreateSolTask>,
+    task_id: String,
+    reward_amount: u64,
+    deadline: Option<i64>,
+    kill_fee_bps: u16,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Validate inputs
+    require!(task_id.len() <= 50, AltruistError::TaskIdTooLong);
+    require!(reward_amount > 0, AltruistError::InvalidRewardAmount);
+    if let Some(deadline) = deadline {
+        require!(deadline > clock.unix_timestamp, AltruistError::InvalidDeadline);
+    }
+    require!(kill_fee_bps <= Task::TOTAL_SHARE_BPS, AltruistError::InvalidKillFee);
+
+    // Validate task_id format (alphanumeric, underscore, hyphen only)
+    require!(
+        task_id.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-'),
+        AltruistError::InvalidTaskIdFormat
+    );
+
+    // Check creator's balance (user-friendly error before transfer)
+    require!(
+        ctx.accounts.creator.lamports() >= reward_amount,
+        AltruistError::InsufficientBalance
+    );
+
+    // Initialize task account
+    let task = &mut ctx.accounts.task;
+    task.task_id = task_id.clone();
+    task.reward_amount = reward_amount;
+    task.status = TaskStatus::Created;
+    task.creator = ctx.accounts.creator.key();
+    task.escrow_account = task.key();
+    task.mint = native_mint::ID;
+    task.token_program = system_program::ID;
+    task.assignees = Vec::new();
+    task.assignee_shares = Vec::new();
+    task.payable_amounts = Vec::new();
+    task.claimed_assignees = Vec::new();
+    task.milestones = Vec::new();
+    task.submissions = Vec::new();
+    task.invitations = Vec::new();
+    task.created_at = clock.unix_timestamp;
+    task.updated_at = clock.unix_timestamp;
+    task.pending_decrease_amount = None;
+    task.decrease_requested_at = None;
+    task.points_faucet = None;
+    task.deadline = deadline;
+    task.arbiter = None;
+    task.disputed = false;
+    task.kill_fee_bps = kill_fee_bps;
+    task.bump = ctx.bumps.task;
+
+    // Transfer reward lamports into the task account
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.creator.to_account_info(),
+        to: ctx.accounts.task.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, reward_amount)?;
+
+    msg!("SOL task created: {} with reward: {} lamports", task_id, reward_amount);
+
+    Ok(())
+}
+
+/// Claim a native SOL reward as an assignee
+pub fn claim_sol_reward(
+    ctx: Context<ClaimSolReward>,
+    _task_id: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
+
+    // Validate task status, assignment and that this assignee hasn't claimed yet
+    task.check_can_claim(&assignee_key)?;
+
+    let claim_amount = task.claim_amount(&assignee_key)?;
+
+    // Check escrowed balance, leaving the task account rent-exempt
+    let task_info = ctx.accounts.task.to_account_info();
+    let escrow_balance = lamports_above_rent(&task_info)?;
+    require!(escrow_balance >= claim_amount, AltruistError::InsufficientEscrowBalance);
+
+    transfer_program_lamports(
+        &task_info,
+        &ctx.accounts.assignee.to_account_info(),
+        claim_amount,
+    )?;
+
+    // Mark this assignee as having claimed their reward
+    let task = &mut ctx.accounts.task;
+    task.mark_claimed(assignee_key)?;
+
+    if task.can_close_account() {
+        msg!("All assignees have claimed their rewards - call close_task to close the account");
+    }
+
+    msg!("Reward of {} lamports claimed by assignee {} ({}/{} assignees have claimed)",
+         claim_amount, assignee_key,
+         task.claimed_assignees.len(), task.assignees.len());
+
+    Ok(())
+}
+
+/// Cancel a native SOL task, refunding the reward and rent to the creator. Cancelling an
+/// `InProgress` task first pays the kill fee to the current assignees, whose wallets
+/// are passed as remaining accounts in assignee order.
+pub fn delete_sol_task<'info>(
+    ctx: Context<'_, '_, '_, 'info, DeleteSolTask<'info>>,
+    task_id: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+
+    // Validate authority and task status
+    require!(
+        task.can_cancel(&ctx.accounts.creator.key()),
+        AltruistError::UnauthorizedTaskCreator
+    );
+
+    let kill_fee = task.kill_fee()?;
+    if kill_fee > 0 {
+        pay_sol_kill_fee(task, kill_fee, ctx.remaining_accounts)?;
+    }
+
+    let task = &mut ctx.accounts.task;
+    task.update_status(TaskStatus::Cancelled);
+
+    // The close constraint returns the escrowed reward together with the rent
+    msg!("SOL task {} cancelled. Lamports refunded to creator and account closed", task_id);
+
+    Ok(())
+}
+
+/// Expire a native SOL task after its deadline, refunding the reward and rent to the
+/// creator. Permissionless so anyone can crank stale tasks. An `InProgress` task still
+/// pays its kill fee to the assignees, whose wallets are passed as remaining accounts
+/// in assignee order.
+pub fn expire_sol_task<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExpireSolTask<'info>>,
+    task_id: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(task.can_expire(clock.unix_timestamp), AltruistError::TaskNotExpired);
+    task.check_not_disputed()?;
+
+    // Letting the deadline pass doesn't avoid the kill fee
+    let kill_fee = task.kill_fee()?;
+    if kill_fee > 0 {
+        pay_sol_kill_fee(task, kill_fee, ctx.remaining_accounts)?;
+    }
+
+    let task = &mut ctx.accounts.task;
+    task.update_status(TaskStatus::Cancelled);
+
+    // The close constraint returns the escrowed reward together with the rent
+    msg!("SOL task {} expired by {}. Lamports refunded to creator and account closed",
+         task_id, ctx.accounts.payer.key());
+
+    Ok(())
+}
+
+/// Pay `kill_fee` lamports from the task account to its assignees, split by their
+/// shares. Their wallets are passed as remaining accounts in assignee order.
+fn pay_sol_kill_fee<'info>(
+    task: &Account<'info, Task>,
+    kill_fee: u64,
+    assignee_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    task.validate_shares()?;
+    require!(
+        assignee_accounts.len() == task.assignees.len(),
+        AltruistError::InvalidAssigneeTokenAccount
+    );
+
+    // Check escrowed balance, leaving the task account rent-exempt
+    let task_info = task.to_account_info();
+    require!(lamports_above_rent(&task_info)? >= kill_fee, AltruistError::InsufficientEscrowBalance);
+
+    let payouts = task.split_amount(kill_fee)?;
+    for ((assignee, account_info), payout) in task.assignees.iter()
+        .zip(assignee_accounts.iter())
+        .zip(payouts)
+    {
+        require!(account_info.key() == *assignee, AltruistError::InvalidAssigneeTokenAccount);
+        transfer_program_lamports(&task_info, account_info, payout)?;
+    }
+
+    msg!("Kill fee of {} lamports paid to {} assignees", kill_fee, task.assignees.len());
+
+    Ok(())
+}
+
+/// Update the reward amount of a native SOL task
+pub fn update_sol_task_reward(
+    ctx: Context<UpdateSolTaskReward>,
+    _task_id: String,
+    new_reward_amount: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let can_modify = ctx.accounts.task.can_modify(&ctx.accounts.creator.key());
+    let current_reward = ctx.accounts.task.reward_amount;
+    let can_initiate_decrease = ctx.accounts.task.can_initiate_decrease(&ctx.accounts.creator.key());
+
+    // Validate authority and basic requirements
+    require!(can_modify, AltruistError::UnauthorizedTaskCreator);
+    require!(new_reward_amount > 0, AltruistError::InvalidRewardAmount);
+
+    if new_reward_amount > current_reward {
+        // INCREASE: Always allowed
+        let additional_amount = new_reward_amount - current_reward;
+        require!(
+            ctx.accounts.creator.lamports() >= additional_amount,
+            AltruistError::InsufficientEscrowBalance
+        );
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.creator.to_account_info(),
+            to: ctx.accounts.task.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, additional_amount)?;
+
+        // Clear any pending decrease since we're increasing
+        let task = &mut ctx.accounts.task;
+        if task.pending_decrease_amount.is_some() {
+            task.cancel_decrease(clock.unix_timestamp);
+        }
+        task.reward_amount = new_reward_amount;
+        task.updated_at = clock.unix_timestamp;
+
+        msg!("Task reward increased to: {} lamports", new_reward_amount);
+    } else if new_reward_amount < current_reward {
+        // DECREASE: Requires time lock for Created status
+        require!(can_initiate_decrease, AltruistError::CannotDecreaseRewardInvalidStatus);
+
+        let task = &mut ctx.accounts.task;
+        task.request_decrease(new_reward_amount, clock.unix_timestamp);
+
+        msg!("Decrease requested to: {} lamports. Time lock period: {} hours",
+             new_reward_amount, Task::DECREASE_TIME_LOCK / 3600);
+    } else {
+        // Same amount - just update timestamp
+        let task = &mut ctx.accounts.task;
+        task.updated_at = clock.unix_timestamp;
+        msg!("Task reward amount unchanged: {} lamports", new_reward_amount);
+    }
+
+    Ok(())
+}
+
+/// Execute a pending reward decrease on a native SOL task after the time lock
+pub fn execute_sol_pending_decrease(
+    ctx: Context<ExecuteSolPendingDecrease>,
+    _task_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let can_execute = ctx.accounts.task.can_execute_decrease(clock.unix_timestamp);
+    let pending_amount = ctx.accounts.task.pending_decrease_amount;
+    let current_reward = ctx.accounts.task.reward_amount;
+
+    require!(can_execute, AltruistError::DecreaseTimeLockNotMet);
+    let new_reward_amount = pending_amount.ok_or(AltruistError::NoPendingDecrease)?;
+    let refund_amount = current_reward - new_reward_amount;
+
+    // Check escrowed balance, leaving the task account rent-exempt
+    let task_info = ctx.accounts.task.to_account_info();
+    let escrow_balance = lamports_above_rent(&task_info)?;
+    require!(escrow_balance >= refund_amount, AltruistError::InsufficientEscrowBalance);
+
+    transfer_program_lamports(&task_info, &ctx.accounts.creator.to_account_info(), refund_amount)?;
+
+    let task = &mut ctx.accounts.task;
+    task.execute_decrease(clock.unix_timestamp);
+
+    msg!("Pending decrease executed. New reward amount: {} lamports", new_reward_amount);
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct CreateSolTask<'info> {
+    #[account(
+        init,
+        payer = creator,
+        space = Task::LEN,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ClaimSolReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        constraint = task.is_native_sol() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub assignee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct DeleteSolTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+        close = creator,  // Close task account and send lamports to creator
+        constraint = task.is_native_sol() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ExpireSolTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        close = creator,  // Close task account and send lamports to creator
+        constraint = task.is_native_sol() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    /// CHECK: Receives the escrowed reward and the closed account's rent
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ AltruistError::InvalidCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    /// Anyone may expire a task once its deadline has passed
+    pub payer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct UpdateSolTaskReward<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+        constraint = task.is_native_sol() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ExecuteSolPendingDecrease<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+        constraint = task.is_native_sol() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
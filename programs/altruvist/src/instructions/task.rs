@@ -2,7 +2,11 @@ use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{self, Mint, TokenAccount, TransferChecked, CloseAccount},
-    token_interface::Token2022,
+    token_interface::TokenInterface,
+    token_interface::spl_token_2022::{
+        extension::{BaseStateWithExtensions, StateWithExtensions},
+        state::Mint as MintState,
+    },
 };
 
 use crate::{
@@ -15,6 +19,9 @@ pub fn create_task(
     ctx: Context<CreateTask>,
     task_id: String,
     reward_amount: u64,
+    deadline: Option<i64>,
+    arbiter: Option<Pubkey>,
+    kill_fee_bps: u16,
 ) -> Result<()> {
     let task = &mut ctx.accounts.task;
     let clock = Clock::get()?;
@@ -22,6 +29,15 @@ pub fn create_task(
     // Validate inputs
     require!(task_id.len() <= 50, AltruistError::TaskIdTooLong);
     require!(reward_amount > 0, AltruistError::InvalidRewardAmount);
+    if let Some(deadline) = deadline {
+        require!(deadline > clock.unix_timestamp, AltruistError::InvalidDeadline);
+    }
+    // An arbiter has to be independent of the creator
+    require!(
+        arbiter != Some(ctx.accounts.creator.key()),
+        AltruistError::InvalidArbiter
+    );
+    require!(kill_fee_bps <= Task::TOTAL_SHARE_BPS, AltruistError::InvalidKillFee);
     
     // Validate task_id format (alphanumeric, underscore, hyphen only)
     require!(
@@ -29,6 +45,15 @@ pub fn create_task(
         AltruistError::InvalidTaskIdFormat
     );
 
+    // Escrow payouts use plain transfers, so mints that withhold a fee, need
+    // transfer-hook accounts or have a permanent delegate are rejected
+    {
+        let mint_info = ctx.accounts.mint.to_account_info();
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<MintState>::unpack(&mint_data)?;
+        Task::check_reward_mint_extensions(&mint_state.get_extension_types()?)?;
+    }
+
     // Check creator's token balance (user-friendly error before transfer)
     let creator_balance = ctx.accounts.creator_token_account.amount;
     require!(creator_balance >= reward_amount, AltruistError::InsufficientBalance);
@@ -39,12 +64,24 @@ pub fn create_task(
     task.status = TaskStatus::Created;
     task.creator = ctx.accounts.creator.key();
     task.escrow_account = ctx.accounts.escrow_token_account.key();
+    task.mint = ctx.accounts.mint.key();
+    task.token_program = ctx.accounts.token_program.key();
     task.assignees = Vec::new(); // Initialize empty assignees vector
+    task.assignee_shares = Vec::new(); // Equal split until shares are set
+    task.payable_amounts = Vec::new(); // Snapshotted on completion
     task.claimed_assignees = Vec::new(); // Initialize empty claimed assignees vector
+    task.milestones = Vec::new();
+    task.submissions = Vec::new();
+    task.invitations = Vec::new();
     task.created_at = clock.unix_timestamp;
     task.updated_at = clock.unix_timestamp;
     task.pending_decrease_amount = None;
     task.decrease_requested_at = None;
+    task.points_faucet = None;
+    task.deadline = deadline;
+    task.arbiter = arbiter;
+    task.disputed = false;
+    task.kill_fee_bps = kill_fee_bps;
     task.bump = ctx.bumps.task;
 
     // Transfer reward tokens to escrow
@@ -59,7 +96,8 @@ pub fn create_task(
     
     token_interface::transfer_checked(cpi_ctx, reward_amount, ctx.accounts.mint.decimals)?;
 
-    msg!("Task created: {} with reward: {} tokens", task_id, reward_amount);
+    msg!("Task created: {} with reward: {} tokens of mint {}",
+         task_id, reward_amount, ctx.accounts.mint.key());
 
     Ok(())
 }
@@ -78,6 +116,8 @@ pub fn update_task_status(
         task.creator == ctx.accounts.creator.key(),
         AltruistError::UnauthorizedTaskCreator
     );
+    // The arbiter decides the outcome of a disputed task
+    task.check_not_disputed()?;
     msg!("Updating task {} status to {:?} -> {:?}", task.task_id, task.status, new_status);
     // Validate status transitions
     match (&task.status, &new_status) {
@@ -104,6 +144,18 @@ pub fn update_task_status(
         }
     }
 
+    // Weighted shares must add up before rewards become claimable, and the
+    // payout is frozen so later changes can't affect claims
+    if matches!(new_status, TaskStatus::Completed) {
+        require!(!task.is_past_deadline(clock.unix_timestamp), AltruistError::TaskDeadlinePassed);
+        require!(
+            task.milestones.iter().all(|m| m.status != MilestoneStatus::Pending),
+            AltruistError::MilestonesNotApproved
+        );
+        task.validate_shares()?;
+        task.snapshot_payable_amounts()?;
+    }
+
     // Update task status
     task.status = new_status.clone();
     task.updated_at = clock.unix_timestamp;
@@ -121,31 +173,18 @@ pub fn claim_reward(
 ) -> Result<()> {
     let task = &ctx.accounts.task;
     
-    // Validate that the task is completed
-    require!(matches!(task.status, TaskStatus::Completed), AltruistError::InvalidTaskStatus);
-    require!(!task.assignees.is_empty(), AltruistError::NoAssignee);
-    
-    // Validate that the signer is one of the assigned users
-    require!(
-        task.is_assignee(&ctx.accounts.assignee.key()),
-        AltruistError::UnauthorizedAssignee
-    );
-
-    // Check if this assignee has already claimed
-    require!(
-        !task.has_claimed(&ctx.accounts.assignee.key()),
-        AltruistError::AlreadyClaimed
-    );
+    // Validate task status, assignment and that this assignee hasn't claimed yet
+    task.check_can_claim(&ctx.accounts.assignee.key())?;
 
     // Store values we need for CPI calls
-    let reward_per_assignee = task.reward_per_assignee();
+    let assignee_key = ctx.accounts.assignee.key();
+    let claim_amount = task.claim_amount(&assignee_key)?;
     let creator_key = task.creator;
     let task_bump = task.bump;
-    let assignee_key = ctx.accounts.assignee.key();
 
     // Check escrow balance
     let escrow_balance = ctx.accounts.escrow_token_account.amount;
-    require!(escrow_balance >= reward_per_assignee, AltruistError::InsufficientEscrowBalance);
+    require!(escrow_balance >= claim_amount, AltruistError::InsufficientEscrowBalance);
 
     // Get the task account info for CPI calls before creating mutable borrow
     let task_account_info = ctx.accounts.task.to_account_info();
@@ -168,15 +207,16 @@ pub fn claim_reward(
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
-    token_interface::transfer_checked(cpi_ctx, reward_per_assignee, ctx.accounts.mint.decimals)?;
+    token_interface::transfer_checked(cpi_ctx, claim_amount, ctx.accounts.mint.decimals)?;
 
     // Mark this assignee as having claimed their reward
     let task = &mut ctx.accounts.task;
     task.mark_claimed(assignee_key)?;
 
-    // Check if escrow is now empty and close if so
-    let remaining_balance = ctx.accounts.escrow_token_account.amount - reward_per_assignee;
-    if remaining_balance == 0 {
+    // Close the escrow once it is empty and nobody is left to claim from it
+    // (milestones can leave zero-amount completion claims)
+    let remaining_balance = ctx.accounts.escrow_token_account.amount - claim_amount;
+    if remaining_balance == 0 && task.all_rewards_claimed() {
         // Create the close account CPI call
         let close_escrow_accounts = CloseAccount {
             account: ctx.accounts.escrow_token_account.to_account_info(),
@@ -203,15 +243,17 @@ pub fn claim_reward(
     }
 
     msg!("Reward of {} tokens claimed by assignee {} ({}/{} assignees have claimed)", 
-         reward_per_assignee, ctx.accounts.assignee.key(),
+         claim_amount, ctx.accounts.assignee.key(),
          claimed_count, total_assignees);
 
     Ok(())
 }
 
-/// Cancel a task and refund creator
-pub fn delete_task(
-    ctx: Context<DeleteTask>,
+/// Cancel a task and refund creator. The current assignees are first paid any approved
+/// milestone payouts they haven't claimed and, for an `InProgress` task, the kill fee;
+/// their token accounts are passed as remaining accounts in assignee order.
+pub fn delete_task<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeleteTask<'info>>,
     task_id: String,
 ) -> Result<()> {
     let task = &ctx.accounts.task;
@@ -223,22 +265,26 @@ pub fn delete_task(
         AltruistError::UnauthorizedTaskCreator
     );
 
+    // An open dispute freezes the escrow until the arbiter resolves it
+    task.check_not_disputed()?;
+
     // Store values we need for CPI calls
-    let reward_amount = task.reward_amount;
+    let kill_fee = task.kill_fee()?;
+    let refund_amount = task.unreleased_amount()? - kill_fee;
+    let assignee_payouts = ctx.accounts.task.cancellation_payouts()?;
+    let payout_total = assignee_payouts.iter()
+        .try_fold(0u64, |total, payout| total.checked_add(*payout))
+        .ok_or(AltruistError::ArithmeticOverflow)?;
+    let task = &ctx.accounts.task;
     let creator_key = task.creator;
     let task_bump = task.bump;
 
     // Check escrow balance (user-friendly error)
     let escrow_balance = ctx.accounts.escrow_token_account.amount;
-    require!(escrow_balance >= reward_amount, AltruistError::InsufficientEscrowBalance);
+    let required_balance = refund_amount.checked_add(payout_total)
+        .ok_or(AltruistError::ArithmeticOverflow)?;
+    require!(escrow_balance >= required_balance, AltruistError::InsufficientEscrowBalance);
 
-    // Refund tokens to creator
-    let cpi_accounts = TransferChecked {
-        from: ctx.accounts.escrow_token_account.to_account_info(),
-        mint: ctx.accounts.mint.to_account_info(),
-        to: ctx.accounts.creator_token_account.to_account_info(),
-        authority: ctx.accounts.task.to_account_info(),
-    };
     let seeds = &[
         b"task".as_ref(),
         task_id.as_bytes(),
@@ -246,10 +292,31 @@ pub fn delete_task(
         &[task_bump]
     ];
     let signer = &[&seeds[..]];
+
+    // Pay the current assignees what they're owed before refunding the rest
+    if payout_total > 0 {
+        pay_assignees(
+            task,
+            &assignee_payouts,
+            ctx.remaining_accounts,
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            signer,
+        )?;
+    }
+
+    // Refund tokens to creator
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.creator_token_account.to_account_info(),
+        authority: ctx.accounts.task.to_account_info(),
+    };
     let cpi_program = ctx.accounts.token_program.to_account_info();
     let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
     
-    token_interface::transfer_checked(cpi_ctx, reward_amount, ctx.accounts.mint.decimals)?;
+    token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.mint.decimals)?;
 
     // Close the escrow token account and return lamports to creator
     let close_escrow_accounts = CloseAccount {
@@ -269,16 +336,63 @@ pub fn delete_task(
     task.update_status(TaskStatus::Cancelled);
     task.updated_at = clock.unix_timestamp;
 
-    msg!("Task {} cancelled. Tokens refunded to creator and accounts closed", task_id);
+    msg!("Task {} cancelled. {} unreleased tokens refunded to creator and accounts closed",
+         task_id, refund_amount);
+
+    Ok(())
+}
+
+/// Pay each of the task's assignees their entry in `payouts` from the escrow.
+/// Their token accounts are passed as remaining accounts in assignee order.
+fn pay_assignees<'info>(
+    task: &Account<'info, Task>,
+    payouts: &[u64],
+    assignee_token_accounts: &'info [AccountInfo<'info>],
+    escrow_token_account: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &Interface<'info, TokenInterface>,
+    signer: &[&[&[u8]]],
+) -> Result<()> {
+    require!(
+        assignee_token_accounts.len() == task.assignees.len(),
+        AltruistError::InvalidAssigneeTokenAccount
+    );
+
+    for ((assignee, account_info), &payout) in task.assignees.iter()
+        .zip(assignee_token_accounts.iter())
+        .zip(payouts)
+    {
+        if payout == 0 {
+            continue;
+        }
+        let assignee_token_account = InterfaceAccount::<TokenAccount>::try_from(account_info)?;
+        require!(
+            assignee_token_account.owner == *assignee && assignee_token_account.mint == task.mint,
+            AltruistError::InvalidAssigneeTokenAccount
+        );
+
+        let cpi_accounts = TransferChecked {
+            from: escrow_token_account.to_account_info(),
+            mint: mint.to_account_info(),
+            to: account_info.clone(),
+            authority: task.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+
+        token_interface::transfer_checked(cpi_ctx, payout, mint.decimals)?;
+    }
+
+    msg!("Paid {:?} tokens to assignees {:?}", payouts, task.assignees);
 
     Ok(())
 }
 
-/// Assign a task to a specific user
+/// Invite a specific user to be assigned to a task
 pub fn assign_task(
     ctx: Context<AssignTask>,
     _task_id: String,
     assignee: Pubkey,
+    share_bps: Option<u16>,
 ) -> Result<()> {
     let task = &mut ctx.accounts.task;
     
@@ -287,6 +401,7 @@ pub fn assign_task(
         task.creator == ctx.accounts.creator.key(),
         AltruistError::UnauthorizedTaskCreator
     );
+    task.check_not_disputed()?;
     
     // Validate task status - can only assign Created tasks
     require!(
@@ -294,10 +409,10 @@ pub fn assign_task(
         AltruistError::InvalidTaskStatus
     );
     
-    // Use the new assign_to method that handles multiple assignees
-    task.assign_to(assignee)?;
+    // The assignment stays pending until the invitee accepts it
+    task.invite(assignee, share_bps)?;
 
-    msg!("Task {} assigned to {}", task.task_id, assignee);
+    msg!("Task {} assignment offered to {}", task.task_id, assignee);
 
     Ok(())
 }
@@ -315,6 +430,7 @@ pub fn remove_assignee_from_task(
         task.creator == ctx.accounts.creator.key(),
         AltruistError::UnauthorizedTaskCreator
     );
+    task.check_not_disputed()?;
     // Validate task status - can only remove assignees from Created or InProgress tasks
     require!(
         matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
@@ -326,11 +442,12 @@ pub fn remove_assignee_from_task(
     Ok(())
 }
 
-/// Assign a task to multiple users at once
+/// Invite multiple users to a task at once, replacing its current assignees
 pub fn assign_task_multiple(
     ctx: Context<AssignTask>,
     _task_id: String,
     assignees: Vec<Pubkey>,
+    shares: Option<Vec<u16>>,
 ) -> Result<()> {
     let task = &mut ctx.accounts.task;
     
@@ -339,6 +456,7 @@ pub fn assign_task_multiple(
         task.creator == ctx.accounts.creator.key(),
         AltruistError::UnauthorizedTaskCreator
     );
+    task.check_not_disputed()?;
     
     // Validate task status - can only assign Created tasks
     require!(
@@ -346,10 +464,158 @@ pub fn assign_task_multiple(
         AltruistError::InvalidTaskStatus
     );
     
-    // Use the new assign_to_multiple method
-    task.assign_to_multiple(assignees.clone())?;
+    let invited_count = assignees.len();
+    task.invite_multiple(assignees, shares)?;
+
+    msg!("Task {} assignment offered to {} users", task.task_id, invited_count);
+
+    Ok(())
+}
+
+/// Accept a pending assignment invitation as the invitee
+pub fn accept_assignment(
+    ctx: Context<RespondToAssignment>,
+    _task_id: String,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
+
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+    task.check_not_disputed()?;
+
+    task.accept_invitation(&assignee_key)?;
+
+    msg!("Task {} assignment accepted by {}", task.task_id, assignee_key);
+
+    Ok(())
+}
+
+/// Decline a pending assignment invitation as the invitee
+pub fn decline_assignment(
+    ctx: Context<RespondToAssignment>,
+    _task_id: String,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
 
-    msg!("Task {} assigned to {} assignees", task.task_id, assignees.len());
+    task.decline_invitation(&assignee_key)?;
+
+    msg!("Task {} assignment declined by {}", task.task_id, assignee_key);
+
+    Ok(())
+}
+
+/// Set the basis-point reward share of every assignee (must sum to 10,000)
+pub fn set_assignee_shares(
+    ctx: Context<AssignTask>,
+    _task_id: String,
+    shares: Vec<u16>,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+
+    // Validate authority - only task creator can set shares
+    require!(
+        task.creator == ctx.accounts.creator.key(),
+        AltruistError::UnauthorizedTaskCreator
+    );
+    task.check_not_disputed()?;
+
+    // Shares are fixed once the task is completed
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.set_shares(shares)?;
+
+    msg!("Task {} assignee shares set to {:?}", task.task_id, task.assignee_shares);
+
+    Ok(())
+}
+
+/// Expire a task after its deadline, refunding the escrow to the creator.
+/// Permissionless so anyone can crank stale tasks. The assignees are first paid any
+/// approved milestone payouts they haven't claimed and, for an `InProgress` task, the
+/// kill fee; their token accounts are passed as remaining accounts in assignee order.
+pub fn expire_task<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExpireTask<'info>>,
+    task_id: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(task.can_expire(clock.unix_timestamp), AltruistError::TaskNotExpired);
+    task.check_not_disputed()?;
+
+    // Store values we need for CPI calls
+    let assignee_payouts = ctx.accounts.task.cancellation_payouts()?;
+    let payout_total = assignee_payouts.iter()
+        .try_fold(0u64, |total, payout| total.checked_add(*payout))
+        .ok_or(AltruistError::ArithmeticOverflow)?;
+    let refund_amount = ctx.accounts.escrow_token_account.amount.checked_sub(payout_total)
+        .ok_or(AltruistError::InsufficientEscrowBalance)?;
+    let task = &ctx.accounts.task;
+    let creator_key = task.creator;
+    let task_bump = task.bump;
+
+    let seeds = &[
+        b"task".as_ref(),
+        task_id.as_bytes(),
+        creator_key.as_ref(),
+        &[task_bump]
+    ];
+    let signer = &[&seeds[..]];
+
+    // Letting the deadline pass doesn't avoid the kill fee or unclaimed milestones
+    if payout_total > 0 {
+        pay_assignees(
+            task,
+            &assignee_payouts,
+            ctx.remaining_accounts,
+            &ctx.accounts.escrow_token_account,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program,
+            signer,
+        )?;
+    }
+
+    // Refund everything left in escrow to the creator
+    if refund_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: ctx.accounts.task.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token_interface::transfer_checked(cpi_ctx, refund_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // Close the escrow token account and return lamports to creator
+    let close_escrow_accounts = CloseAccount {
+        account: ctx.accounts.escrow_token_account.to_account_info(),
+        destination: ctx.accounts.creator.to_account_info(),
+        authority: ctx.accounts.task.to_account_info(),
+    };
+    let close_escrow_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        close_escrow_accounts,
+        signer
+    );
+    token_interface::close_account(close_escrow_ctx)?;
+
+    // Update task status before closing
+    let task = &mut ctx.accounts.task;
+    task.update_status(TaskStatus::Cancelled);
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Task {} expired by {}. {} tokens refunded to creator and accounts closed",
+         task_id, ctx.accounts.payer.key(), refund_amount);
 
     Ok(())
 }
@@ -364,6 +630,48 @@ pub fn close_task(
     // Validate that the task can be closed
     require!(task.can_close_account(), AltruistError::InvalidTaskStatus);
 
+    // Sweep any residual escrow (e.g. tokens sent to it directly) back to the creator
+    if let (Some(escrow), Some(creator_token_account), Some(mint), Some(token_program)) = (
+        &ctx.accounts.escrow_token_account,
+        &ctx.accounts.creator_token_account,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program,
+    ) {
+        let seeds = &[
+            b"task".as_ref(),
+            task.task_id.as_bytes(),
+            task.creator.as_ref(),
+            &[task.bump]
+        ];
+        let signer = &[&seeds[..]];
+
+        let residual = escrow.amount;
+        if residual > 0 {
+            let cpi_accounts = TransferChecked {
+                from: escrow.to_account_info(),
+                mint: mint.to_account_info(),
+                to: creator_token_account.to_account_info(),
+                authority: task.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+            token_interface::transfer_checked(cpi_ctx, residual, mint.decimals)?;
+        }
+
+        let close_escrow_accounts = CloseAccount {
+            account: escrow.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: task.to_account_info(),
+        };
+        let close_escrow_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            close_escrow_accounts,
+            signer
+        );
+        token_interface::close_account(close_escrow_ctx)?;
+
+        msg!("Residual escrow of {} tokens returned to creator and escrow closed", residual);
+    }
+
     msg!("Task {} closed - all assignees have claimed their rewards", task.task_id);
 
     // The task account will be closed by the close constraint
@@ -401,12 +709,15 @@ pub struct CreateTask<'info> {
     )]
     pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        mint::token_program = token_program,
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -453,12 +764,18 @@ pub struct DeleteTask<'info> {
     )]
     pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
@@ -475,6 +792,19 @@ pub struct AssignTask<'info> {
     pub creator: Signer<'info>,
 }
 
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct RespondToAssignment<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    pub assignee: Signer<'info>,
+}
+
 #[derive(Accounts)]
 #[instruction(task_id: String)]
 pub struct ClaimReward<'info> {
@@ -509,12 +839,18 @@ pub struct ClaimReward<'info> {
     )]
     pub creator: UncheckedAccount<'info>,
 
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub assignee: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -537,4 +873,85 @@ pub struct CloseTask<'info> {
         constraint = creator.key() == task.creator @ AltruistError::InvalidCreator
     )]
     pub creator: UncheckedAccount<'info>,
-}
\ No newline at end of file
+
+    /// Residual escrow to sweep; omit when the escrow was already closed by the last claim
+    #[account(
+        mut,
+        address = task.escrow_account,
+        associated_token::mint = mint,
+        associated_token::authority = task,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: Option<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Option<Interface<'info, TokenInterface>>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ExpireTask<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        close = creator,  // Close task account and send lamports to creator
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        address = task.escrow_account,
+        associated_token::mint = mint,
+        associated_token::authority = task,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Receives the refund and the closed accounts' lamports
+    #[account(
+        mut,
+        constraint = creator.key() == task.creator @ AltruistError::InvalidCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Anyone may expire a task once its deadline has passed
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
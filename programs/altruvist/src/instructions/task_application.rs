@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::*,
+    errors::*,
+};
+
+/// Apply to work on a task, or re-apply once an earlier application has been settled
+pub fn apply_to_task(
+    ctx: Context<ApplyToTask>,
+    _task_id: String,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let applicant_key = ctx.accounts.applicant.key();
+    let clock = Clock::get()?;
+
+    // Applications are only accepted while the task is open
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+    require!(applicant_key != task.creator, AltruistError::CannotAssignToCreator);
+    require!(!task.is_assignee(&applicant_key), AltruistError::DuplicateAssignee);
+
+    ctx.accounts.application.open(
+        task.key(),
+        applicant_key,
+        clock.unix_timestamp,
+        ctx.bumps.application,
+    )?;
+
+    msg!("Application to task {} submitted by {}", task.task_id, applicant_key);
+
+    Ok(())
+}
+
+/// Withdraw a pending application as the applicant. The record is kept as withdrawn
+/// and re-opened if they apply again.
+pub fn withdraw_application(
+    ctx: Context<WithdrawApplication>,
+    _task_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let application = &mut ctx.accounts.application;
+    application.settle(ApplicationStatus::Withdrawn, clock.unix_timestamp)?;
+
+    msg!("Application to task {} withdrawn by {}",
+         ctx.accounts.task.task_id, application.applicant);
+
+    Ok(())
+}
+
+/// Accept a pending application, assigning the applicant to the task with
+/// `share_bps` when the task splits its reward by shares
+pub fn accept_application(
+    ctx: Context<ReviewApplication>,
+    _task_id: String,
+    share_bps: Option<u16>,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let application = &mut ctx.accounts.application;
+    let clock = Clock::get()?;
+
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+    task.check_not_disputed()?;
+
+    // Applying is the applicant's consent, so they are assigned directly
+    application.settle(ApplicationStatus::Accepted, clock.unix_timestamp)?;
+    if task.is_invited(&application.applicant) {
+        task.decline_invitation(&application.applicant)?;
+    }
+    match share_bps {
+        Some(share_bps) => task.assign_with_share(application.applicant, share_bps)?,
+        None => task.assign_to(application.applicant)?,
+    }
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Application of {} accepted for task {}", application.applicant, task.task_id);
+
+    Ok(())
+}
+
+/// Reject a pending application. The record is kept as rejected and re-opened if the
+/// applicant applies again.
+pub fn reject_application(
+    ctx: Context<ReviewApplication>,
+    _task_id: String,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    let application = &mut ctx.accounts.application;
+    application.settle(ApplicationStatus::Rejected, clock.unix_timestamp)?;
+
+    msg!("Application of {} rejected for task {}",
+         application.applicant, ctx.accounts.task.task_id);
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ApplyToTask<'info> {
+    #[account(
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        init_if_needed,
+        payer = applicant,
+        space = Application::LEN,
+        seeds = [b"application", task.key().as_ref(), applicant.key().as_ref()],
+        bump
+    )]
+    pub application: Account<'info, Application>,
+
+    #[account(mut)]
+    pub applicant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct WithdrawApplication<'info> {
+    #[account(
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [b"application", task.key().as_ref(), applicant.key().as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+
+    pub applicant: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ReviewApplication<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        seeds = [b"application", task.key().as_ref(), application.applicant.as_ref()],
+        bump = application.bump,
+    )]
+    pub application: Account<'info, Application>,
+
+    pub creator: Signer<'info>,
+}
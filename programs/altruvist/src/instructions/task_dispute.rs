@@ -0,0 +1,148 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TransferChecked},
+    token_interface::TokenInterface,
+};
+
+use crate::{
+    state::*,
+    errors::*,
+};
+
+/// Open a dispute as an assignee, freezing the creator's control over the escrow
+/// until the task arbiter resolves it
+pub fn open_dispute(
+    ctx: Context<OpenDispute>,
+    _task_id: String,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
+    let clock = Clock::get()?;
+
+    task.open_dispute(&assignee_key)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Dispute opened on task {} by assignee {}", task.task_id, assignee_key);
+
+    Ok(())
+}
+
+/// Resolve a dispute as the arbiter: refund `creator_amount` to the creator and let
+/// the assignees claim the rest, split by `shares` if the task wasn't yet completed
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    task_id: String,
+    creator_amount: u64,
+    shares: Option<Vec<u16>>,
+) -> Result<()> {
+    let task = &ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(task.disputed, AltruistError::NoActiveDispute);
+
+    // Store values we need for CPI calls
+    let creator_key = task.creator;
+    let task_bump = task.bump;
+
+    // Check escrow balance (user-friendly error)
+    let escrow_balance = ctx.accounts.escrow_token_account.amount;
+    require!(escrow_balance >= creator_amount, AltruistError::InsufficientEscrowBalance);
+
+    // Refund the creator's portion
+    if creator_amount > 0 {
+        let cpi_accounts = TransferChecked {
+            from: ctx.accounts.escrow_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: ctx.accounts.task.to_account_info(),
+        };
+        let seeds = &[
+            b"task".as_ref(),
+            task_id.as_bytes(),
+            creator_key.as_ref(),
+            &[task_bump]
+        ];
+        let signer = &[&seeds[..]];
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+        token_interface::transfer_checked(cpi_ctx, creator_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // The remaining reward becomes claimable by the assignees
+    let task = &mut ctx.accounts.task;
+    task.resolve_dispute(creator_amount, shares)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Dispute on task {} resolved by arbiter: {} tokens to creator, {} tokens to assignees",
+         task_id, creator_amount, task.payable_amounts.iter().sum::<u64>());
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct OpenDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    pub assignee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+        constraint = task.arbiter == Some(arbiter.key()) @ AltruistError::UnauthorizedArbiter,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        address = task.escrow_account,
+        associated_token::mint = mint,
+        associated_token::authority = task,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = arbiter,
+        associated_token::mint = mint,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Owner of the creator token account receiving the refund
+    #[account(
+        constraint = creator.key() == task.creator @ AltruistError::InvalidCreator
+    )]
+    pub creator: UncheckedAccount<'info>,
+
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub arbiter: Signer<'info>,
+
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
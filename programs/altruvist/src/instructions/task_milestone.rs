@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TransferChecked},
+    token_interface::TokenInterface,
+};
+
+use crate::{
+    state::*,
+    errors::*,
+};
+
+/// Add a milestone paid out of the task's escrowed reward
+pub fn add_milestone(
+    ctx: Context<ManageMilestone>,
+    _task_id: String,
+    title_hash: [u8; 32],
+    amount: u64,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    // Milestones can only be planned while the task is open
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.add_milestone(title_hash, amount)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Milestone {} added to task {} with amount: {} tokens",
+         task.milestones.len() - 1, task.task_id, amount);
+
+    Ok(())
+}
+
+/// Approve a milestone, letting the assignees claim its payout
+pub fn approve_milestone(
+    ctx: Context<ManageMilestone>,
+    _task_id: String,
+    index: u8,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(
+        matches!(task.status, TaskStatus::Created | TaskStatus::InProgress),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.approve_milestone(index as usize)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Milestone {} of task {} approved", index, task.task_id);
+
+    Ok(())
+}
+
+/// Claim an assignee's part of an approved milestone. The escrow stays open for the
+/// completion claims; `claim_reward` and `close_task` close it.
+pub fn claim_milestone(
+    ctx: Context<ClaimMilestone>,
+    task_id: String,
+    index: u8,
+) -> Result<()> {
+    let assignee_key = ctx.accounts.assignee.key();
+
+    // Validate the milestone and record the claim
+    let task = &mut ctx.accounts.task;
+    let claim_amount = task.claim_milestone(index as usize, &assignee_key)?;
+    let creator_key = task.creator;
+    let task_bump = task.bump;
+
+    // Check escrow balance
+    let escrow_balance = ctx.accounts.escrow_token_account.amount;
+    require!(escrow_balance >= claim_amount, AltruistError::InsufficientEscrowBalance);
+
+    let task_account_info = ctx.accounts.task.to_account_info();
+    let seeds = &[
+        b"task".as_ref(),
+        task_id.as_bytes(),
+        creator_key.as_ref(),
+        &[task_bump]
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.escrow_token_account.to_account_info(),
+        mint: ctx.accounts.mint.to_account_info(),
+        to: ctx.accounts.assignee_token_account.to_account_info(),
+        authority: task_account_info,
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+    token_interface::transfer_checked(cpi_ctx, claim_amount, ctx.accounts.mint.decimals)?;
+
+    msg!("Milestone {} payout of {} tokens claimed by assignee {} ({:?})",
+         index, claim_amount, assignee_key, ctx.accounts.task.milestones[index as usize].status);
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ManageMilestone<'info> {
+    /// Milestones are paid from a token escrow, so SOL and impact tasks are excluded
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+        constraint = !task.is_native_sol() && task.points_faucet.is_none() @ AltruistError::InvalidRewardSource,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(mut)]
+    pub creator: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ClaimMilestone<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = task,
+        associated_token::token_program = token_program,
+    )]
+    pub escrow_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = assignee,
+        associated_token::mint = mint,
+        associated_token::authority = assignee,
+        associated_token::token_program = token_program,
+    )]
+    pub assignee_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub assignee: Signer<'info>,
+
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     token_interface::{self, Mint, TokenAccount, TransferChecked},
-    token_interface::Token2022,
+    token_interface::TokenInterface,
 };
 
 use crate::{
@@ -27,6 +27,7 @@ pub fn update_task_reward(
 
     // Validate authority and basic requirements
     require!(can_modify, AltruistError::UnauthorizedTaskCreator);
+    ctx.accounts.task.check_not_disputed()?;
     require!(new_reward_amount > 0, AltruistError::InvalidRewardAmount);
 
     let escrow_balance = ctx.accounts.escrow_token_account.amount;
@@ -61,6 +62,11 @@ pub fn update_task_reward(
     } else if new_reward_amount < current_reward {
         // DECREASE: Requires time lock for Created status
         require!(can_initiate_decrease, AltruistError::CannotDecreaseRewardInvalidStatus);
+        // The reward must still cover every milestone
+        require!(
+            new_reward_amount >= ctx.accounts.task.milestone_total()?,
+            AltruistError::InvalidMilestoneAmount
+        );
         
         // Initiate time-locked decrease
         let task = &mut ctx.accounts.task;
@@ -94,9 +100,14 @@ pub fn execute_pending_decrease(
     let task_bump = ctx.accounts.task.bump;
     
     require!(can_execute, AltruistError::DecreaseTimeLockNotMet);
+    ctx.accounts.task.check_not_disputed()?;
     require!(pending_amount.is_some(), AltruistError::NoPendingDecrease);
     
     let new_reward_amount = pending_amount.unwrap();
+    require!(
+        new_reward_amount >= ctx.accounts.task.milestone_total()?,
+        AltruistError::InvalidMilestoneAmount
+    );
     let refund_amount = current_reward - new_reward_amount;
     
     // Check escrow has enough balance
@@ -186,11 +197,17 @@ pub struct UpdateTaskReward<'info> {
     )]
     pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
@@ -219,11 +236,17 @@ pub struct ExecutePendingDecrease<'info> {
     )]
     pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    #[account(
+        address = task.mint @ AltruistError::InvalidRewardMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     pub creator: Signer<'info>,
 
-    pub token_program: Program<'info, Token2022>,
+    #[account(
+        address = task.token_program @ AltruistError::InvalidTokenProgram
+    )]
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 #[derive(Accounts)]
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::*,
+    errors::*,
+};
+
+/// Record an assignee's deliverable (content hash and URI) for the creator to review
+pub fn submit_work(
+    ctx: Context<SubmitWork>,
+    _task_id: String,
+    content_hash: [u8; 32],
+    uri: String,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let assignee_key = ctx.accounts.assignee.key();
+    let clock = Clock::get()?;
+
+    // Work can be submitted until the task is cancelled
+    require!(
+        !matches!(task.status, TaskStatus::Cancelled),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.submit_work(&assignee_key, content_hash, uri, clock.unix_timestamp)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Work submitted for task {} by assignee {}", task.task_id, assignee_key);
+
+    Ok(())
+}
+
+/// Approve an assignee's submission, allowing them to claim their reward
+pub fn approve_submission(
+    ctx: Context<ReviewSubmission>,
+    _task_id: String,
+    assignee: Pubkey,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(
+        !matches!(task.status, TaskStatus::Cancelled),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.review_submission(&assignee, true)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Submission of {} approved for task {}", assignee, task.task_id);
+
+    Ok(())
+}
+
+/// Send an assignee's submission back for changes
+pub fn request_changes(
+    ctx: Context<ReviewSubmission>,
+    _task_id: String,
+    assignee: Pubkey,
+) -> Result<()> {
+    let task = &mut ctx.accounts.task;
+    let clock = Clock::get()?;
+
+    require!(
+        !matches!(task.status, TaskStatus::Cancelled),
+        AltruistError::InvalidTaskStatus
+    );
+
+    task.review_submission(&assignee, false)?;
+    task.updated_at = clock.unix_timestamp;
+
+    msg!("Changes requested from {} for task {}", assignee, task.task_id);
+
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct SubmitWork<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), task.creator.as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    pub assignee: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(task_id: String)]
+pub struct ReviewSubmission<'info> {
+    #[account(
+        mut,
+        seeds = [b"task", task_id.as_bytes(), creator.key().as_ref()],
+        bump = task.bump,
+    )]
+    pub task: Account<'info, Task>,
+
+    pub creator: Signer<'info>,
+}
@@ -0,0 +1,222 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    spl_token_2022::{
+        extension::{transfer_hook::TransferHookAccount, BaseStateWithExtensions, StateWithExtensions},
+        state::Account as TokenAccountState,
+    },
+    Mint, TokenAccount,
+};
+use spl_tlv_account_resolution::state::ExtraAccountMetaList;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::{
+    utils::transfer_hook_extra_account_metas,
+    state::*,
+    errors::*,
+};
+
+/// Create the account listing the extra accounts Token-2022 passes to `execute`
+pub fn initialize_extra_account_meta_list(
+    ctx: Context<InitializeExtraAccountMetaList>,
+    _faucet_seed: String,
+) -> Result<()> {
+    let extra_account_metas = transfer_hook_extra_account_metas(&ctx.accounts.faucet.key())?;
+
+    let mut data = ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?;
+    ExtraAccountMetaList::init::<ExecuteInstruction>(&mut data, &extra_account_metas)?;
+
+    msg!("Transfer hook accounts initialized for mint: {}", ctx.accounts.mint.key());
+
+    Ok(())
+}
+
+/// Transfer hook invoked by Token-2022 on every transfer of the faucet mint.
+/// When the faucet is transfer-restricted, tokens may only leave the faucet or
+/// move between registered volunteers and charities.
+pub fn execute(ctx: Context<TransferHookExecute>, amount: u64) -> Result<()> {
+    // Only accept calls made by Token-2022 in the middle of a transfer
+    {
+        let source_info = ctx.accounts.source_token.to_account_info();
+        let source_data = source_info.try_borrow_data()?;
+        let source_account = StateWithExtensions::<TokenAccountState>::unpack(&source_data)?;
+        let hook_account = source_account.get_extension::<TransferHookAccount>()?;
+        require!(bool::from(hook_account.transferring), AltruistError::NotTransferring);
+    }
+
+    let faucet = &ctx.accounts.faucet;
+    if !faucet.transfer_restricted || ctx.accounts.source_token.owner == faucet.key() {
+        return Ok(());
+    }
+
+    let is_registered = |participant: &UncheckedAccount| {
+        participant.owner == &crate::ID && !participant.data_is_empty()
+    };
+    require!(
+        is_registered(&ctx.accounts.source_participant),
+        AltruistError::UnregisteredTransferParticipant
+    );
+    require!(
+        is_registered(&ctx.accounts.destination_participant),
+        AltruistError::UnregisteredTransferParticipant
+    );
+
+    msg!("Transfer of {} tokens approved between registered participants", amount);
+
+    Ok(())
+}
+
+/// Register a wallet as a volunteer or charity allowed to hold and move the faucet token
+pub fn register_transfer_participant(
+    ctx: Context<RegisterTransferParticipant>,
+    _faucet_seed: String,
+    wallet: Pubkey,
+    role: ParticipantRole,
+) -> Result<()> {
+    let participant = &mut ctx.accounts.participant;
+    participant.mint = ctx.accounts.mint.key();
+    participant.wallet = wallet;
+    participant.role = role;
+    participant.bump = ctx.bumps.participant;
+
+    msg!("Registered {:?} {} for transfers", role, wallet);
+
+    Ok(())
+}
+
+/// Remove a wallet's transfer registration
+pub fn remove_transfer_participant(
+    _ctx: Context<RemoveTransferParticipant>,
+    _faucet_seed: String,
+    wallet: Pubkey,
+) -> Result<()> {
+    msg!("Removed transfer registration for {}", wallet);
+    Ok(())
+}
+
+// Account validation structs
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        address = faucet.mint,
+        extensions::transfer_hook::program_id = crate::ID,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Initialized in the handler as an `ExtraAccountMetaList`
+    #[account(
+        init,
+        payer = authority,
+        space = ExtraAccountMetaList::size_of(transfer_hook_extra_account_metas(&faucet.key())?.len())?,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Account order is fixed by the transfer hook interface: the four transfer
+/// accounts and the validation account, followed by our extra accounts
+#[derive(Accounts)]
+pub struct TransferHookExecute<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: InterfaceAccount<'info, TokenAccount>,
+
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Source token account owner or delegate
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: Validation account, checked by seeds
+    #[account(
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    #[account(
+        has_one = mint,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    /// CHECK: Registration of the source owner; may not exist
+    #[account(
+        seeds = [b"transfer_participant", mint.key().as_ref(), source_token.owner.as_ref()],
+        bump
+    )]
+    pub source_participant: UncheckedAccount<'info>,
+
+    /// CHECK: Registration of the destination owner; may not exist
+    #[account(
+        seeds = [b"transfer_participant", mint.key().as_ref(), destination_token.owner.as_ref()],
+        bump
+    )]
+    pub destination_participant: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String, wallet: Pubkey)]
+pub struct RegisterTransferParticipant<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        address = faucet.mint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = TransferParticipant::LEN,
+        seeds = [b"transfer_participant", mint.key().as_ref(), wallet.as_ref()],
+        bump
+    )]
+    pub participant: Account<'info, TransferParticipant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(faucet_seed: String, wallet: Pubkey)]
+pub struct RemoveTransferParticipant<'info> {
+    #[account(
+        seeds = [faucet_seed.as_bytes()],
+        bump = faucet.bump,
+        has_one = authority @ AltruistError::UnauthorizedFaucetAuthority,
+    )]
+    pub faucet: Account<'info, Faucet>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"transfer_participant", faucet.mint.as_ref(), wallet.as_ref()],
+        bump = participant.bump
+    )]
+    pub participant: Account<'info, TransferParticipant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
@@ -6,16 +6,21 @@ declare_id!("nWKuomP36HFtZcUTHASRrhZSRvvzUJwnsenaNiXQqjv");
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod merkle;
 pub mod utils;
+pub mod voucher;
 
 use instructions::*;
-use state::TaskStatus;
+use spl_discriminator::SplDiscriminate;
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+use state::{FaucetMetadataField, MintExtensionOptions, ParticipantRole, TaskStatus};
 
 #[program]
 pub mod altruvist {
     use super::*;
 
     /// Initialize the faucet system with Token 2022 mint
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_faucet(
         ctx: Context<InitializeFaucet>,
         faucet_seed: String,
@@ -23,19 +28,66 @@ pub mod altruvist {
         symbol: String,
         uri: String,
         initial_supply: u64,
+        rate_limit: Option<u64>,
+        cooldown_period: i64,
+        max_supply: Option<u64>,
+        decimals: u8,
+        extensions: MintExtensionOptions,
     ) -> Result<()> {
-        instructions::initialize_faucet(ctx, faucet_seed, name, symbol, uri, initial_supply)
+        instructions::initialize_faucet(
+            ctx,
+            faucet_seed,
+            name,
+            symbol,
+            uri,
+            initial_supply,
+            rate_limit,
+            cooldown_period,
+            max_supply,
+            decimals,
+            extensions,
+        )
     }
 
     /// Allow users to request tokens from the faucet
-    pub fn request_tokens(
-        ctx: Context<RequestTokens>,
+    pub fn request_tokens<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestTokens<'info>>,
         faucet_seed: String,
         amount: u64,
     ) -> Result<()> {
         instructions::request_tokens(ctx, faucet_seed, amount)
     }
 
+    /// Request tokens from an allowlisted faucet with a Merkle proof
+    pub fn request_tokens_with_proof<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestTokens<'info>>,
+        faucet_seed: String,
+        amount: u64,
+        allocation: Option<u64>,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::request_tokens_with_proof(ctx, faucet_seed, amount, allocation, proof)
+    }
+
+    /// Redeem an Ed25519-signed voucher issued by the faucet's voucher signer
+    pub fn request_tokens_with_voucher<'info>(
+        ctx: Context<'_, '_, '_, 'info, RequestTokensWithVoucher<'info>>,
+        faucet_seed: String,
+        amount: u64,
+        expiry: i64,
+        nonce: u64,
+    ) -> Result<()> {
+        instructions::request_tokens_with_voucher(ctx, faucet_seed, amount, expiry, nonce)
+    }
+
+    /// Migrate a user's legacy request record to the per-faucet layout
+    pub fn migrate_user_request_record(
+        ctx: Context<MigrateUserRequestRecord>,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::migrate_user_request_record(ctx, faucet_seed)
+    }
+
     /// Delete the faucet and close all associated accounts
     pub fn delete_faucet(ctx: Context<DeleteFaucet>, faucet_seed: String) -> Result<()> {
         instructions::delete_faucet(ctx, faucet_seed)
@@ -46,13 +98,268 @@ pub mod altruvist {
         instructions::burn_and_delete_faucet(ctx, faucet_seed)
     }
 
+    /// Propose a new faucet authority (must be accepted by the new authority)
+    pub fn propose_faucet_authority(
+        ctx: Context<ProposeFaucetAuthority>,
+        faucet_seed: String,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_faucet_authority(ctx, faucet_seed, new_authority)
+    }
+
+    /// Accept a pending faucet authority transfer
+    pub fn accept_faucet_authority(
+        ctx: Context<AcceptFaucetAuthority>,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::accept_faucet_authority(ctx, faucet_seed)
+    }
+
+    /// Migrate a legacy faucet account to the current layout
+    pub fn migrate_faucet(
+        ctx: Context<MigrateFaucet>,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::migrate_faucet(ctx, faucet_seed)
+    }
+
+    /// Update the faucet rate limit and cooldown period
+    pub fn update_faucet_config(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        rate_limit: Option<u64>,
+        cooldown_period: Option<i64>,
+    ) -> Result<()> {
+        instructions::update_faucet_config(ctx, faucet_seed, rate_limit, cooldown_period)
+    }
+
+    /// Set per-user lifetime and window caps on faucet requests
+    pub fn set_faucet_request_caps(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        lifetime_cap: Option<u64>,
+        window_cap: Option<u64>,
+        window_period: i64,
+    ) -> Result<()> {
+        instructions::set_faucet_request_caps(ctx, faucet_seed, lifetime_cap, window_cap, window_period)
+    }
+
+    /// Set or clear the faucet allowlist Merkle root
+    pub fn set_faucet_merkle_root(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        merkle_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::set_faucet_merkle_root(ctx, faucet_seed, merkle_root)
+    }
+
+    /// Set or clear the faucet voucher signer
+    pub fn set_faucet_voucher_signer(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        voucher_signer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_faucet_voucher_signer(ctx, faucet_seed, voucher_signer)
+    }
+
+    /// Pause token requests (emergency stop)
+    pub fn pause_faucet(ctx: Context<UpdateFaucetConfig>, faucet_seed: String) -> Result<()> {
+        instructions::pause_faucet(ctx, faucet_seed)
+    }
+
+    /// Resume token requests
+    pub fn unpause_faucet(ctx: Context<UpdateFaucetConfig>, faucet_seed: String) -> Result<()> {
+        instructions::unpause_faucet(ctx, faucet_seed)
+    }
+
+    /// Mint more tokens into the faucet, up to its maximum supply
+    pub fn mint_to_faucet(
+        ctx: Context<MintToFaucet>,
+        faucet_seed: String,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::mint_to_faucet(ctx, faucet_seed, amount)
+    }
+
+    /// Set or remove the faucet's maximum supply
+    pub fn set_faucet_max_supply(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_faucet_max_supply(ctx, faucet_seed, max_supply)
+    }
+
+    /// Enable or disable the transfer hook's registered-participant rule
+    pub fn set_faucet_transfer_restricted(
+        ctx: Context<UpdateFaucetConfig>,
+        faucet_seed: String,
+        transfer_restricted: bool,
+    ) -> Result<()> {
+        instructions::set_faucet_transfer_restricted(ctx, faucet_seed, transfer_restricted)
+    }
+
+    /// Create the extra account list used by the faucet mint's transfer hook
+    pub fn initialize_extra_account_meta_list(
+        ctx: Context<InitializeExtraAccountMetaList>,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::initialize_extra_account_meta_list(ctx, faucet_seed)
+    }
+
+    /// Transfer hook entrypoint called by Token-2022 on every transfer
+    #[instruction(discriminator = ExecuteInstruction::SPL_DISCRIMINATOR_SLICE)]
+    pub fn execute(ctx: Context<TransferHookExecute>, amount: u64) -> Result<()> {
+        instructions::execute(ctx, amount)
+    }
+
+    /// Register a volunteer or charity wallet for transfer-restricted tokens
+    pub fn register_transfer_participant(
+        ctx: Context<RegisterTransferParticipant>,
+        faucet_seed: String,
+        wallet: Pubkey,
+        role: ParticipantRole,
+    ) -> Result<()> {
+        instructions::register_transfer_participant(ctx, faucet_seed, wallet, role)
+    }
+
+    /// Remove a wallet's transfer registration
+    pub fn remove_transfer_participant(
+        ctx: Context<RemoveTransferParticipant>,
+        faucet_seed: String,
+        wallet: Pubkey,
+    ) -> Result<()> {
+        instructions::remove_transfer_participant(ctx, faucet_seed, wallet)
+    }
+
+    /// Thaw a token account of a default-frozen faucet mint
+    pub fn thaw_faucet_token_account(
+        ctx: Context<ThawFaucetTokenAccount>,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::thaw_faucet_token_account(ctx, faucet_seed)
+    }
+
+    /// Update a token metadata field of the faucet mint
+    pub fn update_faucet_metadata(
+        ctx: Context<UpdateFaucetMetadata>,
+        faucet_seed: String,
+        field: FaucetMetadataField,
+        value: String,
+    ) -> Result<()> {
+        instructions::update_faucet_metadata(ctx, faucet_seed, field, value)
+    }
+
+    /// Remove an additional key-value field from the faucet mint metadata
+    pub fn remove_faucet_metadata_key(
+        ctx: Context<UpdateFaucetMetadata>,
+        faucet_seed: String,
+        key: String,
+    ) -> Result<()> {
+        instructions::remove_faucet_metadata_key(ctx, faucet_seed, key)
+    }
+
     /// Create a new task with escrow
     pub fn create_task(
         ctx: Context<CreateTask>,
         task_id: String,
         reward_amount: u64,
+        deadline: Option<i64>,
+        arbiter: Option<Pubkey>,
+        kill_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::create_task(ctx, task_id, reward_amount, deadline, arbiter, kill_fee_bps)
+    }
+
+    /// Create a task rewarded with impact points minted from a non-transferable faucet
+    pub fn create_impact_task(
+        ctx: Context<CreateImpactTask>,
+        task_id: String,
+        faucet_seed: String,
+        reward_amount: u64,
+        deadline: Option<i64>,
+        kill_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::create_impact_task(ctx, task_id, faucet_seed, reward_amount, deadline, kill_fee_bps)
+    }
+
+    /// Claim impact points for a completed impact task
+    pub fn claim_impact_reward(
+        ctx: Context<ClaimImpactReward>,
+        task_id: String,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::claim_impact_reward(ctx, task_id, faucet_seed)
+    }
+
+    /// Cancel an impact task, minting any kill fee to assignees and releasing its budget
+    pub fn delete_impact_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeleteImpactTask<'info>>,
+        task_id: String,
+        faucet_seed: String,
     ) -> Result<()> {
-        instructions::create_task(ctx, task_id, reward_amount)
+        instructions::delete_impact_task(ctx, task_id, faucet_seed)
+    }
+
+    /// Expire an impact task past its deadline, minting any kill fee and releasing its budget (permissionless)
+    pub fn expire_impact_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireImpactTask<'info>>,
+        task_id: String,
+        faucet_seed: String,
+    ) -> Result<()> {
+        instructions::expire_impact_task(ctx, task_id, faucet_seed)
+    }
+
+    /// Create a task rewarded in native SOL held by the task account
+    pub fn create_sol_task(
+        ctx: Context<CreateSolTask>,
+        task_id: String,
+        reward_amount: u64,
+        deadline: Option<i64>,
+        kill_fee_bps: u16,
+    ) -> Result<()> {
+        instructions::create_sol_task(ctx, task_id, reward_amount, deadline, kill_fee_bps)
+    }
+
+    /// Claim a native SOL reward as an assignee
+    pub fn claim_sol_reward(
+        ctx: Context<ClaimSolReward>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::claim_sol_reward(ctx, task_id)
+    }
+
+    /// Cancel a native SOL task, paying any kill fee to assignees and refunding the creator
+    pub fn delete_sol_task<'info>(
+        ctx: Context<'_, '_, '_, 'info, DeleteSolTask<'info>>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::delete_sol_task(ctx, task_id)
+    }
+
+    /// Expire a native SOL task past its deadline, paying any kill fee and refunding the creator (permissionless)
+    pub fn expire_sol_task<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExpireSolTask<'info>>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::expire_sol_task(ctx, task_id)
+    }
+
+    /// Update the reward amount of a native SOL task
+    pub fn update_sol_task_reward(
+        ctx: Context<UpdateSolTaskReward>,
+        task_id: String,
+        new_reward_amount: u64,
+    ) -> Result<()> {
+        instructions::update_sol_task_reward(ctx, task_id, new_reward_amount)
+    }
+
+    /// Execute a pending reward decrease on a native SOL task
+    pub fn execute_sol_pending_decrease(
+        ctx: Context<ExecuteSolPendingDecrease>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::execute_sol_pending_decrease(ctx, task_id)
     }
 
     /// Update task reward amount
@@ -64,13 +371,14 @@ pub mod altruvist {
         instructions::update_task_reward(ctx, task_id, new_reward_amount)
     }
 
-    /// Assign a task to a specific user
+    /// Invite a user to a task; they must accept before being assigned
     pub fn assign_task(
         ctx: Context<AssignTask>,
         task_id: String,
         assignee: Pubkey,
+        share_bps: Option<u16>,
     ) -> Result<()> {
-        instructions::assign_task(ctx, task_id, assignee)
+        instructions::assign_task(ctx, task_id, assignee, share_bps)
     }
 
     pub fn remove_assignee_from_task(
@@ -81,13 +389,128 @@ pub mod altruvist {
         instructions::remove_assignee_from_task(ctx, task_id, assignee)
     }
 
-    /// Assign a task to multiple users at once
+    /// Invite multiple users to a task at once, replacing its current assignees
     pub fn assign_task_multiple(
         ctx: Context<AssignTask>,
         task_id: String,
         assignees: Vec<Pubkey>,
+        shares: Option<Vec<u16>>,
+    ) -> Result<()> {
+        instructions::assign_task_multiple(ctx, task_id, assignees, shares)
+    }
+
+    /// Apply to work on a task as a volunteer
+    pub fn apply_to_task(
+        ctx: Context<ApplyToTask>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::apply_to_task(ctx, task_id)
+    }
+
+    /// Withdraw a pending task application as the applicant
+    pub fn withdraw_application(
+        ctx: Context<WithdrawApplication>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::withdraw_application(ctx, task_id)
+    }
+
+    /// Accept a pending application, assigning the applicant to the task
+    pub fn accept_application(
+        ctx: Context<ReviewApplication>,
+        task_id: String,
+        share_bps: Option<u16>,
+    ) -> Result<()> {
+        instructions::accept_application(ctx, task_id, share_bps)
+    }
+
+    /// Reject a pending task application
+    pub fn reject_application(
+        ctx: Context<ReviewApplication>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::reject_application(ctx, task_id)
+    }
+
+    /// Accept a pending assignment invitation as the invited user
+    pub fn accept_assignment(
+        ctx: Context<RespondToAssignment>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::accept_assignment(ctx, task_id)
+    }
+
+    /// Decline a pending assignment invitation as the invited user
+    pub fn decline_assignment(
+        ctx: Context<RespondToAssignment>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::decline_assignment(ctx, task_id)
+    }
+
+    /// Set weighted basis-point reward shares for a task's assignees
+    pub fn set_assignee_shares(
+        ctx: Context<AssignTask>,
+        task_id: String,
+        shares: Vec<u16>,
+    ) -> Result<()> {
+        instructions::set_assignee_shares(ctx, task_id, shares)
+    }
+
+    /// Submit an assignee's deliverable (content hash and URI) for review
+    pub fn submit_work(
+        ctx: Context<SubmitWork>,
+        task_id: String,
+        content_hash: [u8; 32],
+        uri: String,
+    ) -> Result<()> {
+        instructions::submit_work(ctx, task_id, content_hash, uri)
+    }
+
+    /// Approve an assignee's submission so they can claim their reward
+    pub fn approve_submission(
+        ctx: Context<ReviewSubmission>,
+        task_id: String,
+        assignee: Pubkey,
+    ) -> Result<()> {
+        instructions::approve_submission(ctx, task_id, assignee)
+    }
+
+    /// Request changes to an assignee's submission
+    pub fn request_changes(
+        ctx: Context<ReviewSubmission>,
+        task_id: String,
+        assignee: Pubkey,
     ) -> Result<()> {
-        instructions::assign_task_multiple(ctx, task_id, assignees)
+        instructions::request_changes(ctx, task_id, assignee)
+    }
+
+    /// Add a milestone with its own partial payout to a task
+    pub fn add_milestone(
+        ctx: Context<ManageMilestone>,
+        task_id: String,
+        title_hash: [u8; 32],
+        amount: u64,
+    ) -> Result<()> {
+        instructions::add_milestone(ctx, task_id, title_hash, amount)
+    }
+
+    /// Approve a task milestone so assignees can claim its payout
+    pub fn approve_milestone(
+        ctx: Context<ManageMilestone>,
+        task_id: String,
+        index: u8,
+    ) -> Result<()> {
+        instructions::approve_milestone(ctx, task_id, index)
+    }
+
+    /// Claim an assignee's payout for an approved milestone
+    pub fn claim_milestone(
+        ctx: Context<ClaimMilestone>,
+        task_id: String,
+        index: u8,
+    ) -> Result<()> {
+        instructions::claim_milestone(ctx, task_id, index)
     }
     
     /// Execute a pending reward decrease after time lock period
@@ -131,11 +554,37 @@ pub mod altruvist {
         instructions::close_task(ctx, task_id)
     }
 
-    /// Cancel a task and refund creator
-    pub fn delete_task(
-        ctx: Context<DeleteTask>,
+    /// Cancel a task, paying any kill fee to assignees and refunding the creator
+    pub fn delete_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeleteTask<'info>>,
         task_id: String,
     ) -> Result<()> {
         instructions::delete_task(ctx, task_id)
     }
+
+    /// Expire a task past its deadline, paying any kill fee and refunding the creator (permissionless)
+    pub fn expire_task<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireTask<'info>>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::expire_task(ctx, task_id)
+    }
+
+    /// Open a dispute over a task as an assignee, freezing the creator's escrow controls
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        task_id: String,
+    ) -> Result<()> {
+        instructions::open_dispute(ctx, task_id)
+    }
+
+    /// Resolve a dispute as the arbiter by splitting the escrow between creator and assignees
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        task_id: String,
+        creator_amount: u64,
+        shares: Option<Vec<u16>>,
+    ) -> Result<()> {
+        instructions::resolve_dispute(ctx, task_id, creator_amount, shares)
+    }
 }
@@ -0,0 +1,143 @@
+use anchor_lang::{
+    prelude::Pubkey,
+    solana_program::hash::hashv,
+};
+
+// Domain separation prefixes so a leaf can never be reinterpreted as an internal node
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Maximum proof length accepted on-chain (supports over a billion leaves)
+pub const MAX_PROOF_LEN: usize = 32;
+
+/// Hash an allowlist entry: the user's pubkey and, optionally, their token allocation
+pub fn leaf_hash(user: &Pubkey, allocation: Option<u64>) -> [u8; 32] {
+    match allocation {
+        Some(amount) => hashv(&[LEAF_PREFIX, user.as_ref(), &amount.to_le_bytes()]).to_bytes(),
+        None => hashv(&[LEAF_PREFIX, user.as_ref()]).to_bytes(),
+    }
+}
+
+/// Hash two sibling nodes; pairs are sorted so proofs don't need to encode positions
+pub fn node_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// Check that `leaf` is included in the tree with the given `root`
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: &[u8; 32]) -> bool {
+    if proof.len() > MAX_PROOF_LEN {
+        return false;
+    }
+    let computed = proof.iter().fold(*leaf, |node, sibling| node_hash(&node, sibling));
+    &computed == root
+}
+
+/// Off-chain helper for building allowlist trees and proofs (used by tests and tooling)
+pub struct MerkleTree {
+    layers: Vec<Vec<[u8; 32]>>,
+}
+
+impl MerkleTree {
+    /// Build a tree from already-hashed leaves (see `leaf_hash`)
+    pub fn new(leaves: Vec<[u8; 32]>) -> Self {
+        let mut layers = vec![leaves];
+        while layers[layers.len() - 1].len() > 1 {
+            let next = layers[layers.len() - 1]
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => node_hash(left, right),
+                    // An unpaired node is promoted to the next layer unchanged
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+        Self { layers }
+    }
+
+    /// Root of the tree (all zeroes for an empty tree)
+    pub fn root(&self) -> [u8; 32] {
+        self.layers
+            .last()
+            .and_then(|layer| layer.first())
+            .copied()
+            .unwrap_or([0; 32])
+    }
+
+    /// Proof for the leaf at `index`, or `None` if the index is out of range
+    pub fn proof(&self, mut index: usize) -> Option<Vec<[u8; 32]>> {
+        if index >= self.layers[0].len() {
+            return None;
+        }
+        let mut proof = Vec::new();
+        for layer in &self.layers[..self.layers.len() - 1] {
+            if let Some(sibling) = layer.get(index ^ 1) {
+                proof.push(*sibling);
+            }
+            index /= 2;
+        }
+        Some(proof)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn users(count: usize) -> Vec<Pubkey> {
+        (0..count).map(|_| Pubkey::new_unique()).collect()
+    }
+
+    #[test]
+    fn proofs_verify_for_every_leaf() {
+        // An odd leaf count exercises the promotion of unpaired nodes
+        let users = users(5);
+        let leaves: Vec<_> = users.iter().map(|user| leaf_hash(user, Some(100))).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            let proof = tree.proof(index).unwrap();
+            assert!(verify_proof(&proof, &tree.root(), leaf));
+        }
+        assert!(tree.proof(leaves.len()).is_none());
+    }
+
+    #[test]
+    fn single_leaf_tree_has_empty_proof() {
+        let leaf = leaf_hash(&Pubkey::new_unique(), None);
+        let tree = MerkleTree::new(vec![leaf]);
+
+        assert_eq!(tree.root(), leaf);
+        assert!(tree.proof(0).unwrap().is_empty());
+        assert!(verify_proof(&[], &tree.root(), &leaf));
+    }
+
+    #[test]
+    fn rejects_tampered_proof() {
+        let users = users(4);
+        let leaves: Vec<_> = users.iter().map(|user| leaf_hash(user, None)).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        let mut proof = tree.proof(1).unwrap();
+        proof[0][0] ^= 1;
+        assert!(!verify_proof(&proof, &tree.root(), &leaves[1]));
+
+        let too_long = vec![[0u8; 32]; MAX_PROOF_LEN + 1];
+        assert!(!verify_proof(&too_long, &tree.root(), &leaves[1]));
+    }
+
+    #[test]
+    fn rejects_wrong_leaf() {
+        let users = users(4);
+        let leaves: Vec<_> = users.iter().map(|user| leaf_hash(user, Some(50))).collect();
+        let tree = MerkleTree::new(leaves);
+        let proof = tree.proof(2).unwrap();
+
+        // A different allocation or a user outside the allowlist must not verify
+        assert!(!verify_proof(&proof, &tree.root(), &leaf_hash(&users[2], Some(51))));
+        assert!(!verify_proof(&proof, &tree.root(), &leaf_hash(&users[2], None)));
+        assert!(!verify_proof(&proof, &tree.root(), &leaf_hash(&Pubkey::new_unique(), Some(50))));
+    }
+}
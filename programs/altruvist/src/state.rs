@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::spl_token_2022::extension::ExtensionType;
 use crate::errors::AltruistError;
 
 /// Faucet PDA that controls the mint authority
@@ -6,7 +7,7 @@ use crate::errors::AltruistError;
 pub struct Faucet {
     /// The mint this faucet controls
     pub mint: Pubkey,
-    /// Authority of the faucet (PDA)
+    /// Admin authority allowed to manage the faucet
     pub authority: Pubkey,
     /// Token account that holds the faucet's tokens
     pub token_account: Pubkey,
@@ -14,6 +15,30 @@ pub struct Faucet {
     pub rate_limit: u64,
     /// Cooldown period between requests (in seconds)
     pub cooldown_period: i64,
+    /// Maximum tokens a single user may receive over the faucet's lifetime
+    pub lifetime_cap: Option<u64>,
+    /// Maximum tokens a single user may receive within one window
+    pub window_cap: Option<u64>,
+    /// Length of the request window (in seconds)
+    pub window_period: i64,
+    /// Root of the allowlist Merkle tree (requests require a proof when set)
+    pub merkle_root: Option<[u8; 32]>,
+    /// Backend key whose Ed25519-signed vouchers the faucet honours
+    pub voucher_signer: Option<Pubkey>,
+    /// Whether token requests are currently halted
+    pub paused: bool,
+    /// Maximum tokens the faucet may ever mint (unbounded when `None`)
+    pub max_supply: Option<u64>,
+    /// Total tokens minted by the faucet, including the initial supply
+    pub total_minted: u64,
+    /// Tokens reserved for impact task budgets but not yet minted
+    pub reserved_budget: u64,
+    /// Whether the mint is non-transferable (tokens are minted straight to users)
+    pub non_transferable: bool,
+    /// Whether the transfer hook only allows transfers between registered participants
+    pub transfer_restricted: bool,
+    /// Authority proposed to take over administration (two-step transfer)
+    pub pending_authority: Option<Pubkey>,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -25,7 +50,98 @@ impl Faucet {
         32 + // token_account
         8 +  // rate_limit
         8 +  // cooldown_period
+        1 + 8 + // lifetime_cap (Option<u64>)
+        1 + 8 + // window_cap (Option<u64>)
+        8 +  // window_period
+        1 + 32 + // merkle_root (Option<[u8; 32]>)
+        1 + 32 + // voucher_signer (Option<Pubkey>)
+        1 +  // paused
+        1 + 8 + // max_supply (Option<u64>)
+        8 +  // total_minted
+        8 +  // reserved_budget
+        1 +  // non_transferable
+        1 +  // transfer_restricted
+        1 + 32 + // pending_authority (Option<Pubkey>)
         1;   // bump
+
+    // Size of faucets created before the admin authority and request controls were added
+    // (mint, authority, token_account, rate_limit, cooldown_period, bump)
+    pub const LEGACY_LEN: usize = 8 + 32 + 32 + 32 + 8 + 8 + 1;
+
+    // Highest mint decimals supported (keeps default amounts well within u64)
+    pub const MAX_DECIMALS: u8 = 9;
+
+    // Longest cooldown an authority may configure (30 days in seconds)
+    pub const MAX_COOLDOWN_PERIOD: i64 = 30 * 24 * 60 * 60;
+
+    // Longest request window an authority may configure (365 days in seconds)
+    pub const MAX_WINDOW_PERIOD: i64 = 365 * 24 * 60 * 60;
+
+    /// Validate rate limit and cooldown settings
+    pub fn validate_config(rate_limit: u64, cooldown_period: i64) -> Result<()> {
+        require!(rate_limit > 0, AltruistError::InvalidRateLimit);
+        require!(
+            (0..=Self::MAX_COOLDOWN_PERIOD).contains(&cooldown_period),
+            AltruistError::InvalidCooldownPeriod
+        );
+        Ok(())
+    }
+
+    /// Default per-request limit of 1000 whole tokens at the given decimals
+    pub fn default_rate_limit(decimals: u8) -> Result<u64> {
+        10u64.checked_pow(decimals as u32)
+            .and_then(|unit| unit.checked_mul(1000))
+            .ok_or(AltruistError::ArithmeticOverflow.into())
+    }
+
+    /// Total minted after minting `amount` more, checked against the supply cap
+    /// (reserved task budgets count towards the cap)
+    pub fn checked_mint_total(&self, amount: u64) -> Result<u64> {
+        let total_minted = self.total_minted.checked_add(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        if let Some(max_supply) = self.max_supply {
+            let committed = total_minted.checked_add(self.reserved_budget)
+                .ok_or(AltruistError::ArithmeticOverflow)?;
+            require!(committed <= max_supply, AltruistError::MaxSupplyExceeded);
+        }
+        Ok(total_minted)
+    }
+
+    /// Reserve supply for an impact task budget
+    pub fn reserve_budget(&mut self, amount: u64) -> Result<()> {
+        self.checked_mint_total(amount)?;
+        self.reserved_budget = self.reserved_budget.checked_add(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Release unused budget back to the faucet's available supply
+    pub fn release_budget(&mut self, amount: u64) -> Result<()> {
+        self.reserved_budget = self.reserved_budget.checked_sub(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Move `amount` from the reserved budget to the minted total
+    pub fn mint_from_budget(&mut self, amount: u64) -> Result<()> {
+        self.release_budget(amount)?;
+        self.total_minted = self.total_minted.checked_add(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Validate per-user lifetime and window caps
+    pub fn validate_caps(lifetime_cap: Option<u64>, window_cap: Option<u64>, window_period: i64) -> Result<()> {
+        require!(lifetime_cap != Some(0), AltruistError::InvalidRequestCap);
+        if let Some(window_cap) = window_cap {
+            require!(window_cap > 0, AltruistError::InvalidRequestCap);
+            require!(
+                window_period > 0 && window_period <= Self::MAX_WINDOW_PERIOD,
+                AltruistError::InvalidRequestCap
+            );
+        }
+        Ok(())
+    }
 }
 
 /// User request tracking for rate limiting
@@ -33,12 +149,18 @@ impl Faucet {
 pub struct UserRequestRecord {
     /// User's public key
     pub user: Pubkey,
+    /// Faucet this record tracks requests for
+    pub faucet: Pubkey,
     /// Timestamp of last request
     pub last_request: i64,
     /// Total tokens received
     pub total_received: u64,
     /// Number of requests made
     pub request_count: u32,
+    /// Start of the user's current request window
+    pub window_start: i64,
+    /// Tokens received within the current request window
+    pub window_received: u64,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -46,12 +168,239 @@ pub struct UserRequestRecord {
 impl UserRequestRecord {
     pub const LEN: usize = 8 + // discriminator
         32 + // user
+        32 + // faucet
         8 +  // last_request
         8 +  // total_received
         4 +  // request_count
+        8 +  // window_start
+        8 +  // window_received
+        1;   // bump
+
+    /// Tokens received in the window containing `current_time`, and that window's start.
+    /// A window opens on the first request after the previous one has elapsed.
+    pub fn current_window(&self, window_period: i64, current_time: i64) -> (i64, u64) {
+        if current_time >= self.window_start.saturating_add(window_period) {
+            (current_time, 0)
+        } else {
+            (self.window_start, self.window_received)
+        }
+    }
+}
+
+/// Layout of user request records created before records were keyed per faucet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct LegacyUserRequestRecord {
+    /// User's public key
+    pub user: Pubkey,
+    /// Timestamp of last request
+    pub last_request: i64,
+    /// Total tokens received
+    pub total_received: u64,
+    /// Number of requests made
+    pub request_count: u32,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl LegacyUserRequestRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // user
+        8 +  // last_request
+        8 +  // total_received
+        4 +  // request_count
+        1;   // bump
+
+    /// Parse a legacy record from raw account data, checking its size and discriminator
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() == Self::LEN && data.starts_with(UserRequestRecord::DISCRIMINATOR),
+            AltruistError::InvalidLegacyUserRecord
+        );
+        Ok(Self::deserialize(&mut &data[8..])?)
+    }
+}
+
+/// Token metadata field an authority can update on the faucet mint
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub enum FaucetMetadataField {
+    Name,
+    Symbol,
+    Uri,
+    /// Additional key-value field
+    Key(String),
+}
+
+/// Optional Token-2022 extensions enabled on the faucet mint at initialization
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct MintExtensionOptions {
+    /// Delegate with unlimited authority over every token account of the mint
+    pub permanent_delegate: Option<Pubkey>,
+    /// New token accounts start frozen and must be thawed by the faucet authority
+    pub default_frozen: bool,
+    /// Tokens cannot be transferred once received
+    pub non_transferable: bool,
+    /// Transfers are validated by this program's transfer hook. Hooked tokens can't fund
+    /// task rewards, since escrow payouts don't pass the hook's extra accounts.
+    pub transfer_hook: bool,
+}
+
+impl MintExtensionOptions {
+    /// Token-2022 extensions required by these options
+    pub fn extension_types(&self) -> Vec<ExtensionType> {
+        let mut extension_types = Vec::new();
+        if self.permanent_delegate.is_some() {
+            extension_types.push(ExtensionType::PermanentDelegate);
+        }
+        if self.default_frozen {
+            extension_types.push(ExtensionType::DefaultAccountState);
+        }
+        if self.non_transferable {
+            extension_types.push(ExtensionType::NonTransferable);
+        }
+        if self.transfer_hook {
+            extension_types.push(ExtensionType::TransferHook);
+        }
+        extension_types
+    }
+}
+
+/// Role of a wallet registered with the faucet mint's transfer hook
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParticipantRole {
+    Volunteer,
+    Charity,
+}
+
+/// Wallet allowed to send and receive a transfer-restricted faucet token
+#[account]
+pub struct TransferParticipant {
+    /// Mint the registration applies to
+    pub mint: Pubkey,
+    /// Registered wallet (token account owner)
+    pub wallet: Pubkey,
+    /// Whether the wallet is a volunteer or a charity
+    pub role: ParticipantRole,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl TransferParticipant {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // mint
+        32 + // wallet
+        1 +  // role
         1;   // bump
 }
 
+/// Redeemed voucher nonce, preventing the same voucher from being claimed twice
+#[account]
+pub struct VoucherRecord {
+    /// Faucet the voucher was issued for
+    pub faucet: Pubkey,
+    /// User who redeemed the voucher
+    pub user: Pubkey,
+    /// Voucher nonce (unique per faucet)
+    pub nonce: u64,
+    /// Amount of tokens redeemed
+    pub amount: u64,
+    /// Redemption timestamp
+    pub redeemed_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl VoucherRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // faucet
+        32 + // user
+        8 +  // nonce
+        8 +  // amount
+        8 +  // redeemed_at
+        1;   // bump
+}
+
+/// Milestone status enumeration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MilestoneStatus {
+    /// Not yet approved; refunded if the task is deleted
+    Pending,
+    /// Approved by the creator; assignees can claim their part
+    Approved,
+    /// Every assignee has claimed their part
+    Paid,
+}
+
+/// Deliverable within a task with its own partial payout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Milestone {
+    /// Hash of the milestone title (the title itself is stored off-chain)
+    pub title_hash: [u8; 32],
+    /// Portion of the task reward paid for this milestone
+    pub amount: u64,
+    /// Current milestone status
+    pub status: MilestoneStatus,
+    /// Bit `i` is set once the assignee at index `i` has claimed
+    pub claimed_mask: u16,
+}
+
+impl Milestone {
+    pub const LEN: usize = 32 + // title_hash
+        8 + // amount
+        1 + // status
+        2; // claimed_mask
+}
+
+/// Review state of an assignee's deliverable
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum SubmissionStatus {
+    /// Nothing submitted yet
+    #[default]
+    NotSubmitted,
+    /// Deliverable submitted and awaiting review
+    Submitted,
+    /// Creator asked for changes; the assignee can resubmit
+    ChangesRequested,
+    /// Creator approved the deliverable; the assignee can claim
+    Approved,
+}
+
+/// Deliverable recorded by an assignee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Submission {
+    /// Current review state
+    pub status: SubmissionStatus,
+    /// Hash of the deliverable content
+    pub content_hash: [u8; 32],
+    /// Where the deliverable can be reviewed (max 200 characters)
+    pub uri: String,
+    /// Timestamp of the latest submission
+    pub submitted_at: i64,
+}
+
+impl Submission {
+    // Maximum length of the deliverable URI
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const LEN: usize = 1 + // status
+        32 + // content_hash
+        4 + Self::MAX_URI_LEN + // uri (String with max 200 characters)
+        8; // submitted_at
+}
+
+/// Pending offer for a user to become a task assignee
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Invitation {
+    /// Invited user, who must accept before being assigned
+    pub assignee: Pubkey,
+    /// Basis-point share applied on acceptance (None for an equal split)
+    pub share_bps: Option<u16>,
+}
+
+impl Invitation {
+    pub const LEN: usize = 32 + // assignee
+        1 + 2; // share_bps (Option<u16>)
+}
+
 /// Task status enumeration
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
 pub enum TaskStatus {
@@ -75,10 +424,25 @@ pub struct Task {
     pub creator: Pubkey,
     /// Escrow token account holding rewards
     pub escrow_account: Pubkey,
+    /// Mint the reward is paid in (the native mint for SOL rewards)
+    pub mint: Pubkey,
+    /// Token program owning the reward mint (SPL Token or Token-2022),
+    /// or the system program for native SOL rewards
+    pub token_program: Pubkey,
     /// List of assignees public keys (max 10 assignees)
     pub assignees: Vec<Pubkey>,
+    /// Basis-point reward share of each entry in `assignees` (empty for an equal split)
+    pub assignee_shares: Vec<u16>,
+    /// Amount payable to each entry in `assignees`, fixed when the task is completed
+    pub payable_amounts: Vec<u64>,
     /// List of assignees who have claimed their rewards
     pub claimed_assignees: Vec<Pubkey>,
+    /// Milestones with partial payouts (max 10 milestones)
+    pub milestones: Vec<Milestone>,
+    /// Deliverable submission of each assignee, parallel to `assignees`
+    pub submissions: Vec<Submission>,
+    /// Assignment invitations awaiting the invitee's acceptance
+    pub invitations: Vec<Invitation>,
     /// Creation timestamp
     pub created_at: i64,
     /// Last updated timestamp
@@ -87,6 +451,17 @@ pub struct Task {
     pub pending_decrease_amount: Option<u64>,
     /// Timestamp when decrease was requested
     pub decrease_requested_at: Option<i64>,
+    /// Impact points faucet that mints rewards directly (no escrow when set)
+    pub points_faucet: Option<Pubkey>,
+    /// Unix timestamp after which the task can no longer be completed and may be expired
+    pub deadline: Option<i64>,
+    /// Optional arbiter who can settle disputes over the escrow
+    pub arbiter: Option<Pubkey>,
+    /// Whether an assignee has opened a dispute that the arbiter hasn't resolved
+    pub disputed: bool,
+    /// Share of the unreleased reward, in basis points, paid to the assignees if the
+    /// creator cancels after work has started
+    pub kill_fee_bps: u16,
     /// Bump seed for PDA derivation
     pub bump: u8,
 }
@@ -94,6 +469,12 @@ pub struct Task {
 impl Task {
     // Maximum number of assignees allowed
     pub const MAX_ASSIGNEES: usize = 10;
+
+    // Maximum number of milestones per task
+    pub const MAX_MILESTONES: usize = 10;
+
+    // Weighted assignee shares must add up to 100% in basis points
+    pub const TOTAL_SHARE_BPS: u16 = 10_000;
     
     pub const LEN: usize = 8 + // discriminator
         4 + 50 + // task_id (String with max 50 chars)
@@ -101,17 +482,38 @@ impl Task {
         1 + 1 + // status (enum + discriminator)
         32 + // creator
         32 + // escrow_account
+        32 + // mint
+        32 + // token_program
         4 + (32 * Self::MAX_ASSIGNEES) + // assignees (Vec<Pubkey> with max 10 entries)
+        4 + (2 * Self::MAX_ASSIGNEES) + // assignee_shares (Vec<u16> with max 10 entries)
+        4 + (8 * Self::MAX_ASSIGNEES) + // payable_amounts (Vec<u64> with max 10 entries)
         4 + (32 * Self::MAX_ASSIGNEES) + // claimed_assignees (Vec<Pubkey> with max 10 entries)
+        4 + (Milestone::LEN * Self::MAX_MILESTONES) + // milestones (Vec<Milestone> with max 10 entries)
+        4 + (Submission::LEN * Self::MAX_ASSIGNEES) + // submissions (Vec<Submission> with max 10 entries)
+        4 + (Invitation::LEN * Self::MAX_ASSIGNEES) + // invitations (Vec<Invitation> with max 10 entries)
         8 + // created_at
         8 + // updated_at
         1 + 8 + // pending_decrease_amount (Option<u64>)
         1 + 8 + // decrease_requested_at (Option<i64>)
+        1 + 32 + // points_faucet (Option<Pubkey>)
+        1 + 8 + // deadline (Option<i64>)
+        1 + 32 + // arbiter (Option<Pubkey>)
+        1 + // disputed
+        2 + // kill_fee_bps
         1; // bump
 
     // Time lock period for decreases (6 hours in seconds)
     pub const DECREASE_TIME_LOCK: i64 = 6 * 60 * 60;
 
+    // Reward mint extensions the escrow can't pay out reliably: transfer fees leave it
+    // short, transfer hooks need extra accounts on every payout, and a permanent
+    // delegate can drain it
+    pub const UNSUPPORTED_REWARD_MINT_EXTENSIONS: [ExtensionType; 3] = [
+        ExtensionType::TransferFeeConfig,
+        ExtensionType::TransferHook,
+        ExtensionType::PermanentDelegate,
+    ];
+
     /// Check if task can be modified by the given authority
     pub fn can_modify(&self, authority: &Pubkey) -> bool {
         &self.creator == authority && matches!(self.status, TaskStatus::Created | TaskStatus::InProgress)
@@ -122,6 +524,121 @@ impl Task {
         matches!(self.status, TaskStatus::Created | TaskStatus::InProgress) && !self.assignees.is_empty()
     }
 
+    /// Check if the task deadline has passed
+    pub fn is_past_deadline(&self, current_time: i64) -> bool {
+        self.deadline.is_some_and(|deadline| current_time > deadline)
+    }
+
+    /// Check if an open task has passed its deadline and can be expired by anyone
+    pub fn can_expire(&self, current_time: i64) -> bool {
+        self.is_past_deadline(current_time)
+            && !matches!(self.status, TaskStatus::Completed | TaskStatus::Cancelled)
+    }
+
+    /// Fail if an open dispute freezes the creator's control over the escrow
+    pub fn check_not_disputed(&self) -> Result<()> {
+        require!(!self.disputed, AltruistError::TaskDisputed);
+        Ok(())
+    }
+
+    /// Open a dispute as an assignee, either on an unfinished task or on a completed
+    /// task whose submissions the creator hasn't all approved
+    pub fn open_dispute(&mut self, assignee: &Pubkey) -> Result<()> {
+        require!(self.arbiter.is_some(), AltruistError::NoArbiter);
+        require!(self.is_assignee(assignee), AltruistError::UnauthorizedAssignee);
+        match self.status {
+            TaskStatus::Cancelled => return Err(AltruistError::InvalidTaskStatus.into()),
+            TaskStatus::Completed => require!(
+                self.submissions.iter().any(|s| s.status != SubmissionStatus::Approved),
+                AltruistError::InvalidTaskStatus
+            ),
+            _ => {}
+        }
+        self.check_not_disputed()?;
+
+        self.disputed = true;
+        Ok(())
+    }
+
+    /// Settle a dispute by returning `creator_amount` to the creator.
+    ///
+    /// On an unfinished task the amount comes out of the unreleased reward and the rest
+    /// is split between the assignees by `shares` (an equal split if none are given and
+    /// the current shares are incomplete), completing the task. On a completed task it
+    /// comes out of the payouts of the assignees whose submissions weren't approved,
+    /// and the rest is divided between them in proportion to their original payouts.
+    pub fn resolve_dispute(&mut self, creator_amount: u64, shares: Option<Vec<u16>>) -> Result<()> {
+        require!(self.disputed, AltruistError::NoActiveDispute);
+
+        if matches!(self.status, TaskStatus::Completed) {
+            self.settle_completed_dispute(creator_amount)?;
+        } else {
+            require!(
+                creator_amount <= self.unreleased_amount()?,
+                AltruistError::InvalidDisputeSplit
+            );
+
+            match shares {
+                Some(shares) => self.set_shares(shares)?,
+                // Declined invitations or removals can leave shares incomplete
+                None if self.validate_shares().is_err() => self.assignee_shares = Vec::new(),
+                None => {}
+            }
+
+            // Unapproved milestones are settled by the split rather than paid separately
+            self.milestones.retain(|m| m.status != MilestoneStatus::Pending);
+            self.reward_amount = self.reward_amount.checked_sub(creator_amount)
+                .ok_or(AltruistError::ArithmeticOverflow)?;
+            self.snapshot_payable_amounts()?;
+            self.status = TaskStatus::Completed;
+        }
+
+        for submission in self.submissions.iter_mut() {
+            submission.status = SubmissionStatus::Approved;
+        }
+        self.disputed = false;
+        Ok(())
+    }
+
+    fn settle_completed_dispute(&mut self, creator_amount: u64) -> Result<()> {
+        let disputed: Vec<usize> = (0..self.assignees.len())
+            .filter(|&index| {
+                self.submissions.get(index)
+                    .is_some_and(|s| s.status != SubmissionStatus::Approved)
+            })
+            .collect();
+        let pool = disputed.iter()
+            .try_fold(0u64, |total, &index| total.checked_add(self.payable_amounts[index]))
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        require!(creator_amount <= pool, AltruistError::InvalidDisputeSplit);
+
+        let remaining = pool - creator_amount;
+        let mut allocated = 0u64;
+        for (position, &index) in disputed.iter().enumerate() {
+            let payout = if position + 1 == disputed.len() || pool == 0 {
+                remaining - allocated
+            } else {
+                (remaining as u128 * self.payable_amounts[index] as u128 / pool as u128) as u64
+            };
+            allocated += payout;
+            self.payable_amounts[index] = payout;
+        }
+
+        self.reward_amount = self.reward_amount.checked_sub(creator_amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Kill fee owed to the assignees if the task were cancelled now. Only applies
+    /// once work has started on an assigned task.
+    pub fn kill_fee(&self) -> Result<u64> {
+        if !matches!(self.status, TaskStatus::InProgress) || self.assignees.is_empty() {
+            return Ok(0);
+        }
+        let unreleased_amount = self.unreleased_amount()?;
+        Ok((unreleased_amount as u128 * self.kill_fee_bps as u128 / Self::TOTAL_SHARE_BPS as u128) as u64)
+    }
+
     /// Check if task can be cancelled
     pub fn can_cancel(&self, authority: &Pubkey) -> bool {
         &self.creator == authority && !matches!(self.status, TaskStatus::Completed | TaskStatus::Cancelled)
@@ -141,14 +658,57 @@ impl Task {
         }
     }
 
+    /// Reject reward mints with any extension the escrow can't support
+    pub fn check_reward_mint_extensions(extension_types: &[ExtensionType]) -> Result<()> {
+        require!(
+            !extension_types
+                .iter()
+                .any(|extension| Self::UNSUPPORTED_REWARD_MINT_EXTENSIONS.contains(extension)),
+            AltruistError::UnsupportedRewardMint
+        );
+        Ok(())
+    }
+
     /// Update the task status and timestamp
     pub fn update_status(&mut self, new_status: TaskStatus) {
         self.status = new_status;
         self.updated_at = Clock::get().unwrap().unix_timestamp;
     }
 
-    /// Assign task to multiple users
-    pub fn assign_to_multiple(&mut self, assignees: Vec<Pubkey>) -> Result<()> {
+    /// Invite a single user to the task. Only assignees who accept count towards
+    /// completion and reward splits.
+    pub fn invite(&mut self, assignee: Pubkey, share_bps: Option<u16>) -> Result<()> {
+        require!(
+            self.assignees.len() + self.invitations.len() < Self::MAX_ASSIGNEES,
+            AltruistError::TooManyAssignees
+        );
+        require!(assignee != self.creator, AltruistError::CannotAssignToCreator);
+        require!(
+            !self.assignees.contains(&assignee) && !self.is_invited(&assignee),
+            AltruistError::DuplicateAssignee
+        );
+        // Weighted and equal-split assignments can't be mixed on one task
+        if let Some(weighted) = self.share_mode() {
+            require!(weighted == share_bps.is_some(), AltruistError::MixedShareModes);
+        }
+
+        self.invitations.push(Invitation { assignee, share_bps });
+        Ok(())
+    }
+
+    /// Whether the task's current assignees and invitations use basis-point shares,
+    /// or None while there are neither
+    fn share_mode(&self) -> Option<bool> {
+        if !self.assignees.is_empty() {
+            return Some(!self.assignee_shares.is_empty());
+        }
+        self.invitations.first().map(|invitation| invitation.share_bps.is_some())
+    }
+
+    /// Replace the task's assignees with invitations to multiple users, all of them
+    /// using `shares` or all of them splitting equally
+    pub fn invite_multiple(&mut self, assignees: Vec<Pubkey>, shares: Option<Vec<u16>>) -> Result<()> {
+        require!(!self.has_unclaimed_milestones(), AltruistError::MilestonePayoutPending);
         require!(assignees.len() <= Self::MAX_ASSIGNEES, AltruistError::TooManyAssignees);
         require!(!assignees.is_empty(), AltruistError::NoAssignees);
         
@@ -162,28 +722,152 @@ impl Task {
             // Ensure assignee is not the creator
             require!(assignee != &self.creator, AltruistError::CannotAssignToCreator);
         }
+
+        if let Some(shares) = &shares {
+            require!(shares.len() == assignees.len(), AltruistError::InvalidAssigneeShares);
+            require!(
+                Self::shares_total(shares) == Self::TOTAL_SHARE_BPS as u32,
+                AltruistError::InvalidAssigneeShares
+            );
+        }
         
-        self.assignees = assignees;
+        self.invitations = assignees.into_iter()
+            .enumerate()
+            .map(|(index, assignee)| Invitation {
+                assignee,
+                share_bps: shares.as_ref().map(|shares| shares[index]),
+            })
+            .collect();
+        self.assignees = Vec::new();
+        self.assignee_shares = Vec::new();
+        self.submissions = Vec::new();
+        Ok(())
+    }
+
+    /// Check if a user has a pending invitation
+    pub fn is_invited(&self, user: &Pubkey) -> bool {
+        self.invitations.iter().any(|invitation| &invitation.assignee == user)
+    }
+
+    fn take_invitation(&mut self, assignee: &Pubkey) -> Result<Invitation> {
+        let index = self.invitations.iter().position(|invitation| &invitation.assignee == assignee)
+            .ok_or(AltruistError::NoPendingInvitation)?;
+        Ok(self.invitations.remove(index))
+    }
+
+    /// Accept a pending invitation, becoming an assignee
+    pub fn accept_invitation(&mut self, assignee: &Pubkey) -> Result<()> {
+        let invitation = self.take_invitation(assignee)?;
+        match invitation.share_bps {
+            Some(share_bps) => self.assign_with_share(invitation.assignee, share_bps),
+            None => self.assign_to(invitation.assignee),
+        }
+    }
+
+    /// Decline a pending invitation
+    pub fn decline_invitation(&mut self, assignee: &Pubkey) -> Result<()> {
+        self.take_invitation(assignee)?;
         Ok(())
     }
 
     /// Add a single assignee to the task
     pub fn assign_to(&mut self, assignee: Pubkey) -> Result<()> {
-        require!(self.assignees.len() < Self::MAX_ASSIGNEES, AltruistError::TooManyAssignees);
+        require!(!self.has_unclaimed_milestones(), AltruistError::MilestonePayoutPending);
+        require!(self.assignee_shares.is_empty(), AltruistError::InvalidAssigneeShares);
+        require!(
+            self.invitations.iter().all(|invitation| invitation.share_bps.is_none()),
+            AltruistError::MixedShareModes
+        );
+        // Pending invitations hold their slots until they are answered
+        require!(
+            self.assignees.len() + self.invitations.len() < Self::MAX_ASSIGNEES,
+            AltruistError::TooManyAssignees
+        );
         require!(assignee != self.creator, AltruistError::CannotAssignToCreator);
         require!(!self.assignees.contains(&assignee), AltruistError::DuplicateAssignee);
         
         self.assignees.push(assignee);
+        self.submissions.push(Submission::default());
         Ok(())
     }
 
+    /// Add a single assignee with a basis-point share. Shares only need to sum to
+    /// `TOTAL_SHARE_BPS` once the task is completed, so they can be built up one by one.
+    pub fn assign_with_share(&mut self, assignee: Pubkey, share_bps: u16) -> Result<()> {
+        require!(!self.has_unclaimed_milestones(), AltruistError::MilestonePayoutPending);
+        require!(
+            self.assignee_shares.len() == self.assignees.len(),
+            AltruistError::InvalidAssigneeShares
+        );
+        require!(
+            self.invitations.iter().all(|invitation| invitation.share_bps.is_some()),
+            AltruistError::MixedShareModes
+        );
+        require!(
+            self.assignees.len() + self.invitations.len() < Self::MAX_ASSIGNEES,
+            AltruistError::TooManyAssignees
+        );
+        require!(assignee != self.creator, AltruistError::CannotAssignToCreator);
+        require!(!self.assignees.contains(&assignee), AltruistError::DuplicateAssignee);
+
+        self.assignees.push(assignee);
+        self.assignee_shares.push(share_bps);
+        self.submissions.push(Submission::default());
+        Ok(())
+    }
+
+    /// Remove an assignee, or revoke a pending invitation
     pub fn remove_assignee(&mut self, assignee: &Pubkey) -> Result<()> {
-        require!(self.is_assignee(assignee), AltruistError::UnauthorizedAssignee);
+        if self.is_invited(assignee) {
+            return self.decline_invitation(assignee);
+        }
+        require!(!self.has_unclaimed_milestones(), AltruistError::MilestonePayoutPending);
+        // Dropping accepted assignees mid-task would let the creator cancel without a kill fee
+        require!(
+            self.kill_fee_bps == 0 || !matches!(self.status, TaskStatus::InProgress),
+            AltruistError::AssigneeRemovalLocked
+        );
+        let index = self.assignees.iter().position(|a| a == assignee)
+            .ok_or(AltruistError::UnauthorizedAssignee)?;
         
-        self.assignees.retain(|a| a != assignee);
+        self.assignees.remove(index);
+        if !self.assignee_shares.is_empty() {
+            self.assignee_shares.remove(index);
+        }
+        self.submissions.remove(index);
         Ok(())
     }
 
+    /// Set the basis-point share of every assignee (an empty list restores an equal split)
+    pub fn set_shares(&mut self, shares: Vec<u16>) -> Result<()> {
+        require!(!self.has_unclaimed_milestones(), AltruistError::MilestonePayoutPending);
+        if !shares.is_empty() {
+            require!(shares.len() == self.assignees.len(), AltruistError::InvalidAssigneeShares);
+            require!(
+                Self::shares_total(&shares) == Self::TOTAL_SHARE_BPS as u32,
+                AltruistError::InvalidAssigneeShares
+            );
+        }
+        self.assignee_shares = shares;
+        Ok(())
+    }
+
+    /// Validate that the configured shares cover every assignee and sum to 100%
+    pub fn validate_shares(&self) -> Result<()> {
+        if !self.assignee_shares.is_empty() {
+            require!(
+                self.assignee_shares.len() == self.assignees.len()
+                    && Self::shares_total(&self.assignee_shares) == Self::TOTAL_SHARE_BPS as u32,
+                AltruistError::InvalidAssigneeShares
+            );
+        }
+        Ok(())
+    }
+
+    fn shares_total(shares: &[u16]) -> u32 {
+        shares.iter().map(|&share| share as u32).sum()
+    }
+
     /// Check if a user is assigned to this task
     pub fn is_assignee(&self, user: &Pubkey) -> bool {
         self.assignees.contains(user)
@@ -194,13 +878,45 @@ impl Task {
         self.assignees.len()
     }
 
-    /// Calculate reward per assignee
-    pub fn reward_per_assignee(&self) -> u64 {
-        if self.assignees.is_empty() {
-            0
-        } else {
-            self.reward_amount / self.assignees.len() as u64
+    /// Split `amount` between the assignees by their shares (or equally), with
+    /// rounding dust going to the last assignee so the parts sum to `amount`
+    pub fn split_amount(&self, amount: u64) -> Result<Vec<u64>> {
+        let count = self.assignees.len() as u64;
+        let mut parts: Vec<u64> = (0..self.assignees.len())
+            .map(|index| match self.assignee_shares.get(index) {
+                Some(&share_bps) => {
+                    (amount as u128 * share_bps as u128 / Self::TOTAL_SHARE_BPS as u128) as u64
+                }
+                None => amount / count,
+            })
+            .collect();
+        let allocated = parts.iter()
+            .try_fold(0u64, |total, &part| total.checked_add(part))
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        if let Some(last) = parts.last_mut() {
+            *last += amount.checked_sub(allocated)
+                .ok_or(AltruistError::ArithmeticOverflow)?;
         }
+        Ok(parts)
+    }
+
+    /// Fix each assignee's payable amount from the reward not covered by milestones
+    pub fn snapshot_payable_amounts(&mut self) -> Result<()> {
+        let completion_amount = self.reward_amount.checked_sub(self.milestone_total()?)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        self.payable_amounts = self.split_amount(completion_amount)?;
+        // The payout is final, so a pending decrease can no longer apply
+        self.pending_decrease_amount = None;
+        self.decrease_requested_at = None;
+        Ok(())
+    }
+
+    /// Reward owed to `assignee`, as snapshotted when the task was completed
+    pub fn claim_amount(&self, assignee: &Pubkey) -> Result<u64> {
+        let index = self.assignees.iter().position(|a| a == assignee)
+            .ok_or(AltruistError::UnauthorizedAssignee)?;
+        self.payable_amounts.get(index).copied()
+            .ok_or(AltruistError::InvalidTaskStatus.into())
     }
 
     /// Request a decrease in reward amount
@@ -227,6 +943,77 @@ impl Task {
         self.updated_at = current_time;
     }
 
+    /// Check if the reward is native SOL held as lamports in the task account
+    pub fn is_native_sol(&self) -> bool {
+        self.token_program == System::id()
+    }
+
+    /// Validate that `assignee` may claim their reward now
+    pub fn check_can_claim(&self, assignee: &Pubkey) -> Result<()> {
+        require!(matches!(self.status, TaskStatus::Completed), AltruistError::InvalidTaskStatus);
+        require!(!self.assignees.is_empty(), AltruistError::NoAssignee);
+        require!(self.is_assignee(assignee), AltruistError::UnauthorizedAssignee);
+        require!(!self.has_claimed(assignee), AltruistError::AlreadyClaimed);
+        require!(
+            self.submission_of(assignee)?.status == SubmissionStatus::Approved,
+            AltruistError::SubmissionNotApproved
+        );
+        Ok(())
+    }
+
+    /// Submission recorded for `assignee`
+    pub fn submission_of(&self, assignee: &Pubkey) -> Result<&Submission> {
+        let index = self.assignees.iter().position(|a| a == assignee)
+            .ok_or(AltruistError::UnauthorizedAssignee)?;
+        self.submissions.get(index)
+            .ok_or(AltruistError::InvalidSubmissionStatus.into())
+    }
+
+    fn submission_of_mut(&mut self, assignee: &Pubkey) -> Result<&mut Submission> {
+        let index = self.assignees.iter().position(|a| a == assignee)
+            .ok_or(AltruistError::UnauthorizedAssignee)?;
+        self.submissions.get_mut(index)
+            .ok_or(AltruistError::InvalidSubmissionStatus.into())
+    }
+
+    /// Record (or replace) an assignee's deliverable for review
+    pub fn submit_work(
+        &mut self,
+        assignee: &Pubkey,
+        content_hash: [u8; 32],
+        uri: String,
+        current_time: i64,
+    ) -> Result<()> {
+        require!(uri.len() <= Submission::MAX_URI_LEN, AltruistError::SubmissionUriTooLong);
+        let submission = self.submission_of_mut(assignee)?;
+        require!(
+            submission.status != SubmissionStatus::Approved,
+            AltruistError::InvalidSubmissionStatus
+        );
+
+        submission.status = SubmissionStatus::Submitted;
+        submission.content_hash = content_hash;
+        submission.uri = uri;
+        submission.submitted_at = current_time;
+        Ok(())
+    }
+
+    /// Approve a submitted deliverable, or send it back for changes
+    pub fn review_submission(&mut self, assignee: &Pubkey, approved: bool) -> Result<()> {
+        let submission = self.submission_of_mut(assignee)?;
+        require!(
+            submission.status == SubmissionStatus::Submitted,
+            AltruistError::InvalidSubmissionStatus
+        );
+
+        submission.status = if approved {
+            SubmissionStatus::Approved
+        } else {
+            SubmissionStatus::ChangesRequested
+        };
+        Ok(())
+    }
+
     /// Check if an assignee has already claimed their reward
     pub fn has_claimed(&self, assignee: &Pubkey) -> bool {
         self.claimed_assignees.contains(assignee)
@@ -248,6 +1035,840 @@ impl Task {
 
     /// Check if the task account can be closed (all rewards claimed and escrow empty)
     pub fn can_close_account(&self) -> bool {
-        matches!(self.status, TaskStatus::Completed) && self.all_rewards_claimed()
+        matches!(self.status, TaskStatus::Completed)
+            && self.all_rewards_claimed()
+            && self.milestones.iter().all(|m| m.status == MilestoneStatus::Paid)
+    }
+
+    /// Sum of all milestone amounts
+    pub fn milestone_total(&self) -> Result<u64> {
+        self.milestones.iter()
+            .try_fold(0u64, |total, m| total.checked_add(m.amount))
+            .ok_or(AltruistError::ArithmeticOverflow.into())
+    }
+
+    /// Portion of the reward not yet released through approved milestones
+    pub fn unreleased_amount(&self) -> Result<u64> {
+        let released = self.milestones.iter()
+            .filter(|m| m.status != MilestoneStatus::Pending)
+            .try_fold(0u64, |total, m| total.checked_add(m.amount))
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        self.reward_amount.checked_sub(released)
+            .ok_or(AltruistError::ArithmeticOverflow.into())
+    }
+
+    /// Check if an approved milestone still has assignees waiting to claim
+    pub fn has_unclaimed_milestones(&self) -> bool {
+        self.milestones.iter().any(|m| m.status == MilestoneStatus::Approved)
+    }
+
+    /// Add a milestone paid out of the task reward
+    pub fn add_milestone(&mut self, title_hash: [u8; 32], amount: u64) -> Result<()> {
+        require!(amount > 0, AltruistError::InvalidRewardAmount);
+        require!(self.milestones.len() < Self::MAX_MILESTONES, AltruistError::TooManyMilestones);
+        let total = self.milestone_total()?.checked_add(amount)
+            .ok_or(AltruistError::ArithmeticOverflow)?;
+        require!(total <= self.reward_amount, AltruistError::InvalidMilestoneAmount);
+
+        self.milestones.push(Milestone {
+            title_hash,
+            amount,
+            status: MilestoneStatus::Pending,
+            claimed_mask: 0,
+        });
+        Ok(())
+    }
+
+    /// Approve a pending milestone, releasing its amount to the assignees
+    pub fn approve_milestone(&mut self, index: usize) -> Result<()> {
+        require!(!self.assignees.is_empty(), AltruistError::NoAssignee);
+        self.validate_shares()?;
+        let milestone = self.milestones.get_mut(index)
+            .ok_or(AltruistError::InvalidMilestone)?;
+        require!(milestone.status == MilestoneStatus::Pending, AltruistError::InvalidMilestoneStatus);
+
+        milestone.status = MilestoneStatus::Approved;
+        Ok(())
+    }
+
+    /// Record `assignee`'s claim on an approved milestone and return their payout
+    pub fn claim_milestone(&mut self, index: usize, assignee: &Pubkey) -> Result<u64> {
+        let assignee_index = self.assignees.iter().position(|a| a == assignee)
+            .ok_or(AltruistError::UnauthorizedAssignee)?;
+        let milestone = self.milestones.get(index)
+            .ok_or(AltruistError::InvalidMilestone)?;
+        require!(milestone.status == MilestoneStatus::Approved, AltruistError::InvalidMilestoneStatus);
+        let assignee_bit = 1u16 << assignee_index;
+        require!(milestone.claimed_mask & assignee_bit == 0, AltruistError::AlreadyClaimed);
+
+        let payout = self.split_amount(milestone.amount)?[assignee_index];
+
+        let all_claimed_mask = (1u16 << self.assignees.len()) - 1;
+        let milestone = &mut self.milestones[index];
+        milestone.claimed_mask |= assignee_bit;
+        if milestone.claimed_mask == all_claimed_mask {
+            milestone.status = MilestoneStatus::Paid;
+        }
+        Ok(payout)
+    }
+
+    /// Mark every approved milestone as paid and return, per assignee, the milestone
+    /// payouts they had not claimed yet. Used when the task is cancelled or expires.
+    pub fn settle_unclaimed_milestones(&mut self) -> Result<Vec<u64>> {
+        let mut payouts = vec![0u64; self.assignees.len()];
+        let all_claimed_mask = (1u16 << self.assignees.len()) - 1;
+        for index in 0..self.milestones.len() {
+            if self.milestones[index].status != MilestoneStatus::Approved {
+                continue;
+            }
+            let split = self.split_amount(self.milestones[index].amount)?;
+            let milestone = &mut self.milestones[index];
+            for (assignee_index, (payout, share)) in payouts.iter_mut().zip(split).enumerate() {
+                if milestone.claimed_mask & (1u16 << assignee_index) == 0 {
+                    *payout = payout.checked_add(share)
+                        .ok_or(AltruistError::ArithmeticOverflow)?;
+                }
+            }
+            milestone.claimed_mask = all_claimed_mask;
+            milestone.status = MilestoneStatus::Paid;
+        }
+        Ok(payouts)
+    }
+
+    /// Per-assignee payouts owed when the task is cancelled or expires: unclaimed
+    /// milestone payouts plus the kill fee split by share. Settles the milestones.
+    pub fn cancellation_payouts(&mut self) -> Result<Vec<u64>> {
+        let kill_fee = self.kill_fee()?;
+        let mut payouts = self.settle_unclaimed_milestones()?;
+        if kill_fee > 0 {
+            self.validate_shares()?;
+            for (payout, fee) in payouts.iter_mut().zip(self.split_amount(kill_fee)?) {
+                *payout = payout.checked_add(fee)
+                    .ok_or(AltruistError::ArithmeticOverflow)?;
+            }
+        }
+        Ok(payouts)
+    }
+}
+
+/// Application status enumeration, mirroring the off-chain `taskApplications` model
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ApplicationStatus {
+    Pending,
+    Accepted,
+    Rejected,
+    Withdrawn,
+}
+
+/// A volunteer's application to work on a task
+#[account]
+pub struct Application {
+    /// Task applied to
+    pub task: Pubkey,
+    /// Applicant wallet
+    pub applicant: Pubkey,
+    /// Current application status
+    pub status: ApplicationStatus,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// Last updated timestamp
+    pub updated_at: i64,
+    /// Bump seed for PDA derivation
+    pub bump: u8,
+}
+
+impl Application {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // task
+        32 + // applicant
+        1 +  // status
+        8 +  // created_at
+        8 +  // updated_at
+        1;   // bump
+
+    /// Open the application, either freshly created or re-opened after an earlier one was
+    /// settled. Settled records are kept on-chain, so only a pending one blocks re-applying.
+    pub fn open(&mut self, task: Pubkey, applicant: Pubkey, current_time: i64, bump: u8) -> Result<()> {
+        let is_new = self.applicant == Pubkey::default();
+        require!(
+            is_new || self.status != ApplicationStatus::Pending,
+            AltruistError::InvalidApplicationStatus
+        );
+        self.task = task;
+        self.applicant = applicant;
+        self.status = ApplicationStatus::Pending;
+        if is_new {
+            self.created_at = current_time;
+        }
+        self.updated_at = current_time;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Move a pending application to its final status
+    pub fn settle(&mut self, status: ApplicationStatus, current_time: i64) -> Result<()> {
+        require!(
+            self.status == ApplicationStatus::Pending,
+            AltruistError::InvalidApplicationStatus
+        );
+        self.status = status;
+        self.updated_at = current_time;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_err<T: std::fmt::Debug>(result: Result<T>, error: AltruistError) {
+        assert_eq!(result.unwrap_err(), error.into());
+    }
+
+    #[test]
+    fn faucet_config_bounds() {
+        assert!(Faucet::validate_config(1, 0).is_ok());
+        assert!(Faucet::validate_config(u64::MAX, Faucet::MAX_COOLDOWN_PERIOD).is_ok());
+        assert_err(Faucet::validate_config(0, 60), AltruistError::InvalidRateLimit);
+        assert_err(Faucet::validate_config(1, -1), AltruistError::InvalidCooldownPeriod);
+        assert_err(
+            Faucet::validate_config(1, Faucet::MAX_COOLDOWN_PERIOD + 1),
+            AltruistError::InvalidCooldownPeriod,
+        );
+    }
+
+    #[test]
+    fn faucet_default_rate_limit() {
+        assert_eq!(Faucet::default_rate_limit(0).unwrap(), 1_000);
+        assert_eq!(Faucet::default_rate_limit(6).unwrap(), 1_000_000_000);
+        assert_eq!(
+            Faucet::default_rate_limit(Faucet::MAX_DECIMALS).unwrap(),
+            1_000_000_000_000
+        );
+        assert_err(Faucet::default_rate_limit(19), AltruistError::ArithmeticOverflow);
+    }
+
+    #[test]
+    fn legacy_user_records_are_parsed_from_the_old_layout() {
+        let legacy = LegacyUserRequestRecord {
+            user: Pubkey::new_unique(),
+            last_request: 1_700_000_000,
+            total_received: 500,
+            request_count: 3,
+            bump: 254,
+        };
+        let mut data = UserRequestRecord::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), LegacyUserRequestRecord::LEN);
+
+        let parsed = LegacyUserRequestRecord::try_from_account_data(&data).unwrap();
+        assert_eq!(parsed.user, legacy.user);
+        assert_eq!(parsed.last_request, legacy.last_request);
+        assert_eq!(parsed.total_received, legacy.total_received);
+        assert_eq!(parsed.request_count, legacy.request_count);
+        assert_eq!(parsed.bump, legacy.bump);
+
+        // Current-layout records and other accounts are rejected
+        let mut current = data.clone();
+        current.resize(UserRequestRecord::LEN, 0);
+        assert_err(
+            LegacyUserRequestRecord::try_from_account_data(&current),
+            AltruistError::InvalidLegacyUserRecord,
+        );
+        let mut other = data.clone();
+        other[..8].copy_from_slice(Faucet::DISCRIMINATOR);
+        assert_err(
+            LegacyUserRequestRecord::try_from_account_data(&other),
+            AltruistError::InvalidLegacyUserRecord,
+        );
+        assert_err(
+            LegacyUserRequestRecord::try_from_account_data(&[]),
+            AltruistError::InvalidLegacyUserRecord,
+        );
+    }
+
+    #[test]
+    fn faucet_request_cap_bounds() {
+        assert!(Faucet::validate_caps(None, None, 0).is_ok());
+        assert!(Faucet::validate_caps(Some(1), Some(1), Faucet::MAX_WINDOW_PERIOD).is_ok());
+        assert_err(Faucet::validate_caps(Some(0), None, 0), AltruistError::InvalidRequestCap);
+        assert_err(Faucet::validate_caps(None, Some(0), 60), AltruistError::InvalidRequestCap);
+        // A window cap needs a window to apply to
+        assert_err(Faucet::validate_caps(None, Some(10), 0), AltruistError::InvalidRequestCap);
+        assert_err(
+            Faucet::validate_caps(None, Some(10), Faucet::MAX_WINDOW_PERIOD + 1),
+            AltruistError::InvalidRequestCap,
+        );
+    }
+
+    #[test]
+    fn request_window_rolls_over_once_elapsed() {
+        let record = UserRequestRecord {
+            user: Pubkey::new_unique(),
+            faucet: Pubkey::new_unique(),
+            last_request: 1_000,
+            total_received: 500,
+            request_count: 2,
+            window_start: 1_000,
+            window_received: 300,
+            bump: 255,
+        };
+
+        assert_eq!(record.current_window(100, 1_050), (1_000, 300));
+        assert_eq!(record.current_window(100, 1_099), (1_000, 300));
+        assert_eq!(record.current_window(100, 1_100), (1_100, 0));
+        // Without a window every request starts a fresh one
+        assert_eq!(record.current_window(0, 1_000), (1_000, 0));
+    }
+
+    fn faucet(max_supply: Option<u64>, total_minted: u64) -> Faucet {
+        Faucet {
+            mint: Pubkey::new_unique(),
+            authority: Pubkey::new_unique(),
+            token_account: Pubkey::new_unique(),
+            rate_limit: 1_000,
+            cooldown_period: 60,
+            lifetime_cap: None,
+            window_cap: None,
+            window_period: 0,
+            merkle_root: None,
+            voucher_signer: None,
+            paused: false,
+            max_supply,
+            total_minted,
+            reserved_budget: 0,
+            non_transferable: false,
+            transfer_restricted: false,
+            pending_authority: None,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn mint_total_respects_max_supply() {
+        let uncapped = faucet(None, u64::MAX - 1);
+        assert_eq!(uncapped.checked_mint_total(1).unwrap(), u64::MAX);
+        assert_err(uncapped.checked_mint_total(2), AltruistError::ArithmeticOverflow);
+
+        let capped = faucet(Some(1_000), 600);
+        assert_eq!(capped.checked_mint_total(400).unwrap(), 1_000);
+        assert_err(capped.checked_mint_total(401), AltruistError::MaxSupplyExceeded);
+    }
+
+    #[test]
+    fn extension_types_match_requested_options() {
+        assert!(MintExtensionOptions::default().extension_types().is_empty());
+
+        let options = MintExtensionOptions {
+            permanent_delegate: Some(Pubkey::new_unique()),
+            default_frozen: true,
+            non_transferable: true,
+            transfer_hook: true,
+        };
+        assert_eq!(
+            options.extension_types(),
+            vec![
+                ExtensionType::PermanentDelegate,
+                ExtensionType::DefaultAccountState,
+                ExtensionType::NonTransferable,
+                ExtensionType::TransferHook,
+            ]
+        );
+
+        let options = MintExtensionOptions { default_frozen: true, ..Default::default() };
+        assert_eq!(options.extension_types(), vec![ExtensionType::DefaultAccountState]);
+    }
+
+    #[test]
+    fn budget_reservations_count_towards_max_supply() {
+        let mut faucet = faucet(Some(1_000), 400);
+
+        faucet.reserve_budget(500).unwrap();
+        assert_eq!(faucet.reserved_budget, 500);
+        // Only 100 tokens are left once the reservation is accounted for
+        assert_err(faucet.checked_mint_total(101), AltruistError::MaxSupplyExceeded);
+        assert_err(faucet.reserve_budget(101), AltruistError::MaxSupplyExceeded);
+
+        faucet.mint_from_budget(200).unwrap();
+        assert_eq!((faucet.total_minted, faucet.reserved_budget), (600, 300));
+
+        faucet.release_budget(300).unwrap();
+        assert_eq!(faucet.reserved_budget, 0);
+        assert_err(faucet.release_budget(1), AltruistError::ArithmeticOverflow);
+        assert_eq!(faucet.checked_mint_total(400).unwrap(), 1_000);
+    }
+
+    fn task(reward_amount: u64, assignee_count: usize) -> (Task, Vec<Pubkey>) {
+        let mut task = Task {
+            task_id: "task-1".to_string(),
+            reward_amount,
+            status: TaskStatus::Created,
+            creator: Pubkey::new_unique(),
+            escrow_account: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            token_program: Pubkey::new_unique(),
+            assignees: Vec::new(),
+            assignee_shares: Vec::new(),
+            payable_amounts: Vec::new(),
+            claimed_assignees: Vec::new(),
+            milestones: Vec::new(),
+            submissions: Vec::new(),
+            invitations: Vec::new(),
+            created_at: 0,
+            updated_at: 0,
+            pending_decrease_amount: None,
+            decrease_requested_at: None,
+            points_faucet: None,
+            deadline: None,
+            arbiter: None,
+            disputed: false,
+            kill_fee_bps: 0,
+            bump: 255,
+        };
+        let assignees: Vec<Pubkey> = (0..assignee_count).map(|_| Pubkey::new_unique()).collect();
+        for assignee in &assignees {
+            task.assign_to(*assignee).unwrap();
+        }
+        (task, assignees)
+    }
+
+    fn approve(task: &mut Task, assignee: &Pubkey) {
+        task.submit_work(assignee, [1; 32], "https://example.org/work".to_string(), 0).unwrap();
+        task.review_submission(assignee, true).unwrap();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn claims_require_completed_task_and_approved_submission() {
+        let (mut task, assignees) = task(1_000, 2);
+        let outsider = Pubkey::new_unique();
+
+        assert_err(task.check_can_claim(&assignees[0]), AltruistError::InvalidTaskStatus);
+
+        task.status = TaskStatus::Completed;
+        assert_err(task.check_can_claim(&outsider), AltruistError::UnauthorizedAssignee);
+        assert_err(task.check_can_claim(&assignees[0]), AltruistError::SubmissionNotApproved);
+
+        approve(&mut task, &assignees[0]);
+        assert!(task.check_can_claim(&assignees[0]).is_ok());
+
+        task.mark_claimed(assignees[0]).unwrap();
+        assert_err(task.check_can_claim(&assignees[0]), AltruistError::AlreadyClaimed);
+        assert!(!task.all_rewards_claimed());
+    }
+
+    #[test]
+    fn claims_require_an_assignee() {
+        let (mut task, _) = task(1_000, 0);
+        task.status = TaskStatus::Completed;
+
+        assert_err(task.check_can_claim(&Pubkey::new_unique()), AltruistError::NoAssignee);
+    }
+
+    #[test]
+    fn reward_mints_reject_unsupported_extensions() {
+        assert!(Task::check_reward_mint_extensions(&[]).is_ok());
+        assert!(Task::check_reward_mint_extensions(&[
+            ExtensionType::MetadataPointer,
+            ExtensionType::TokenMetadata,
+            ExtensionType::DefaultAccountState,
+        ])
+        .is_ok());
+        for extension in Task::UNSUPPORTED_REWARD_MINT_EXTENSIONS {
+            assert_err(
+                Task::check_reward_mint_extensions(&[ExtensionType::MetadataPointer, extension]),
+                AltruistError::UnsupportedRewardMint,
+            );
+        }
+        // Faucet mints created with a transfer hook can't fund tasks
+        let hooked = MintExtensionOptions { transfer_hook: true, ..Default::default() };
+        assert_err(
+            Task::check_reward_mint_extensions(&hooked.extension_types()),
+            AltruistError::UnsupportedRewardMint,
+        );
+    }
+
+    #[test]
+    fn split_gives_rounding_dust_to_last_assignee() {
+        let (mut task, _) = task(1_000, 3);
+        assert_eq!(task.split_amount(100).unwrap(), vec![33, 33, 34]);
+
+        task.set_shares(vec![3_333, 3_333, 3_334]).unwrap();
+        assert_eq!(task.split_amount(10).unwrap(), vec![3, 3, 4]);
+        assert_eq!(task.split_amount(u64::MAX).unwrap().iter().sum::<u64>(), u64::MAX);
+
+        let (empty, _) = self::task(1_000, 0);
+        assert!(empty.split_amount(100).unwrap().is_empty());
+    }
+
+    #[test]
+    fn shares_must_cover_every_assignee_and_sum_to_total() {
+        let (mut task, _) = task(1_000, 3);
+        assert!(task.validate_shares().is_ok());
+
+        assert_err(task.set_shares(vec![5_000, 5_000]), AltruistError::InvalidAssigneeShares);
+        assert_err(task.set_shares(vec![5_000, 3_000, 1_000]), AltruistError::InvalidAssigneeShares);
+
+        task.set_shares(vec![5_000, 3_000, 2_000]).unwrap();
+        assert!(task.validate_shares().is_ok());
+
+        // Shares left incomplete by a removal no longer validate
+        let removed = task.assignees[2];
+        task.remove_assignee(&removed).unwrap();
+        assert_eq!(task.assignee_shares, vec![5_000, 3_000]);
+        assert_err(task.validate_shares(), AltruistError::InvalidAssigneeShares);
+
+        // An empty list restores the equal split
+        task.set_shares(Vec::new()).unwrap();
+        assert!(task.validate_shares().is_ok());
+    }
+
+    #[test]
+    fn weighted_assignees_are_built_up_one_by_one() {
+        let (mut task, _) = task(1_000, 0);
+
+        task.assign_with_share(Pubkey::new_unique(), 6_000).unwrap();
+        assert_err(task.validate_shares(), AltruistError::InvalidAssigneeShares);
+        task.assign_with_share(Pubkey::new_unique(), 4_000).unwrap();
+        assert!(task.validate_shares().is_ok());
+
+        // An equal-split assignee can't join a weighted task
+        assert_err(task.assign_to(Pubkey::new_unique()), AltruistError::InvalidAssigneeShares);
+    }
+
+    #[test]
+    fn snapshot_fixes_payouts_and_drops_pending_decrease() {
+        let (mut task, assignees) = task(1_000, 3);
+        task.request_decrease(600, 10);
+
+        task.snapshot_payable_amounts().unwrap();
+        assert_eq!(task.payable_amounts, vec![333, 333, 334]);
+        assert_eq!(task.pending_decrease_amount, None);
+        assert_eq!(task.decrease_requested_at, None);
+        assert_eq!(task.claim_amount(&assignees[2]).unwrap(), 334);
+        assert_err(task.claim_amount(&Pubkey::new_unique()), AltruistError::UnauthorizedAssignee);
+    }
+
+    #[test]
+    fn snapshot_excludes_milestone_amounts() {
+        let (mut task, _) = task(1_000, 2);
+        task.set_shares(vec![7_500, 2_500]).unwrap();
+        task.add_milestone([0; 32], 200).unwrap();
+
+        task.snapshot_payable_amounts().unwrap();
+        assert_eq!(task.payable_amounts, vec![600, 200]);
+    }
+
+    #[test]
+    fn milestones_pay_each_assignee_once() {
+        let (mut task, assignees) = task(1_000, 2);
+
+        task.add_milestone([0; 32], 301).unwrap();
+        assert_err(task.add_milestone([0; 32], 700), AltruistError::InvalidMilestoneAmount);
+        assert_err(task.claim_milestone(0, &assignees[0]), AltruistError::InvalidMilestoneStatus);
+
+        task.approve_milestone(0).unwrap();
+        assert_eq!(task.unreleased_amount().unwrap(), 699);
+        assert!(task.has_unclaimed_milestones());
+        // Assignees can't change while a milestone awaits its claims
+        assert_err(task.assign_to(Pubkey::new_unique()), AltruistError::MilestonePayoutPending);
+
+        assert_eq!(task.claim_milestone(0, &assignees[0]).unwrap(), 150);
+        assert_err(task.claim_milestone(0, &assignees[0]), AltruistError::AlreadyClaimed);
+        assert_eq!(task.claim_milestone(0, &assignees[1]).unwrap(), 151);
+        assert_eq!(task.milestones[0].status, MilestoneStatus::Paid);
+        assert!(!task.has_unclaimed_milestones());
+    }
+
+    #[test]
+    fn cancelling_settles_unclaimed_milestone_payouts() {
+        let (mut task, assignees) = task(1_000, 2);
+        task.add_milestone([0; 32], 301).unwrap();
+        task.add_milestone([1; 32], 100).unwrap();
+        task.add_milestone([2; 32], 50).unwrap();
+        task.approve_milestone(0).unwrap();
+        task.approve_milestone(1).unwrap();
+        task.claim_milestone(0, &assignees[0]).unwrap();
+
+        // Once work has started the kill fee on the unreleased 599 is added on top
+        let mut in_progress = task.clone();
+        in_progress.status = TaskStatus::InProgress;
+        in_progress.kill_fee_bps = 1_000;
+        assert_eq!(in_progress.cancellation_payouts().unwrap(), vec![79, 231]);
+
+        // Only the shares nobody has claimed yet are paid out; pending milestones stay put
+        assert_eq!(task.settle_unclaimed_milestones().unwrap(), vec![50, 201]);
+        assert!(!task.has_unclaimed_milestones());
+        assert_eq!(task.milestones[2].status, MilestoneStatus::Pending);
+        assert_eq!(task.settle_unclaimed_milestones().unwrap(), vec![0, 0]);
+        assert_eq!(task.unreleased_amount().unwrap(), 599);
+    }
+
+    #[test]
+    fn deadlines_only_expire_open_tasks() {
+        let (mut task, _) = task(1_000, 1);
+        assert!(!task.is_past_deadline(i64::MAX));
+        assert!(!task.can_expire(i64::MAX));
+
+        task.deadline = Some(100);
+        assert!(!task.is_past_deadline(100));
+        assert!(task.is_past_deadline(101));
+        assert!(!task.can_expire(100));
+        assert!(task.can_expire(101));
+
+        task.status = TaskStatus::InProgress;
+        assert!(task.can_expire(101));
+        for status in [TaskStatus::Completed, TaskStatus::Cancelled] {
+            task.status = status;
+            assert!(!task.can_expire(101));
+        }
+    }
+
+    #[test]
+    fn submissions_go_through_review() {
+        let (mut task, assignees) = task(1_000, 1);
+        let assignee = &assignees[0];
+
+        assert_err(task.review_submission(assignee, true), AltruistError::InvalidSubmissionStatus);
+        assert_err(
+            task.submit_work(assignee, [1; 32], "x".repeat(Submission::MAX_URI_LEN + 1), 5),
+            AltruistError::SubmissionUriTooLong,
+        );
+        assert_err(
+            task.submit_work(&Pubkey::new_unique(), [1; 32], String::new(), 5),
+            AltruistError::UnauthorizedAssignee,
+        );
+
+        task.submit_work(assignee, [1; 32], "https://example.org/v1".to_string(), 5).unwrap();
+        task.review_submission(assignee, false).unwrap();
+        assert_eq!(task.submission_of(assignee).unwrap().status, SubmissionStatus::ChangesRequested);
+
+        task.submit_work(assignee, [2; 32], "https://example.org/v2".to_string(), 9).unwrap();
+        task.review_submission(assignee, true).unwrap();
+        let submission = task.submission_of(assignee).unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Approved);
+        assert_eq!((submission.content_hash, submission.submitted_at), ([2; 32], 9));
+        assert_eq!(submission.uri, "https://example.org/v2");
+
+        // Approved work is final
+        assert_err(
+            task.submit_work(assignee, [3; 32], String::new(), 10),
+            AltruistError::InvalidSubmissionStatus,
+        );
+    }
+
+    fn arbitrated_task(reward_amount: u64, assignee_count: usize) -> (Task, Vec<Pubkey>) {
+        let (mut task, assignees) = task(reward_amount, assignee_count);
+        task.arbiter = Some(Pubkey::new_unique());
+        task.status = TaskStatus::InProgress;
+        (task, assignees)
+    }
+
+    #[test]
+    fn disputes_are_opened_by_assignees_with_an_arbiter() {
+        let (mut task, assignees) = task(1_000, 1);
+        assert_err(task.open_dispute(&assignees[0]), AltruistError::NoArbiter);
+
+        let (mut task, assignees) = arbitrated_task(1_000, 2);
+        assert_err(task.open_dispute(&Pubkey::new_unique()), AltruistError::UnauthorizedAssignee);
+
+        task.open_dispute(&assignees[0]).unwrap();
+        assert_err(task.check_not_disputed(), AltruistError::TaskDisputed);
+        assert_err(task.open_dispute(&assignees[1]), AltruistError::TaskDisputed);
+
+        task.disputed = false;
+        task.status = TaskStatus::Cancelled;
+        assert_err(task.open_dispute(&assignees[0]), AltruistError::InvalidTaskStatus);
+    }
+
+    #[test]
+    fn completed_tasks_can_only_be_disputed_over_unapproved_work() {
+        let (mut task, assignees) = arbitrated_task(1_000, 1);
+        task.status = TaskStatus::Completed;
+        task.open_dispute(&assignees[0]).unwrap();
+
+        task.disputed = false;
+        approve(&mut task, &assignees[0]);
+        assert_err(task.open_dispute(&assignees[0]), AltruistError::InvalidTaskStatus);
+    }
+
+    #[test]
+    fn resolving_an_open_task_splits_the_unreleased_reward() {
+        let (mut task, assignees) = arbitrated_task(1_000, 2);
+        assert_err(task.resolve_dispute(0, None), AltruistError::NoActiveDispute);
+
+        task.add_milestone([0; 32], 100).unwrap();
+        task.approve_milestone(0).unwrap();
+        task.add_milestone([1; 32], 50).unwrap();
+        task.open_dispute(&assignees[0]).unwrap();
+        assert_err(task.resolve_dispute(901, None), AltruistError::InvalidDisputeSplit);
+
+        task.resolve_dispute(300, None).unwrap();
+        // The pending milestone is folded into the split; the approved one is still claimable
+        assert_eq!(task.milestones.len(), 1);
+        assert_eq!(task.reward_amount, 700);
+        assert_eq!(task.payable_amounts, vec![300, 300]);
+        assert_eq!(task.status, TaskStatus::Completed);
+        assert!(!task.disputed);
+        assert!(task.check_can_claim(&assignees[1]).is_ok());
+    }
+
+    #[test]
+    fn resolving_an_open_task_applies_the_arbiters_shares() {
+        let (mut task, assignees) = arbitrated_task(1_000, 2);
+        task.open_dispute(&assignees[1]).unwrap();
+        assert_err(task.resolve_dispute(0, Some(vec![9_000])), AltruistError::InvalidAssigneeShares);
+
+        task.resolve_dispute(0, Some(vec![8_000, 2_000])).unwrap();
+        assert_eq!(task.payable_amounts, vec![800, 200]);
+    }
+
+    #[test]
+    fn resolving_with_incomplete_shares_falls_back_to_an_equal_split() {
+        let (mut task, _) = arbitrated_task(1_000, 0);
+        let assignees: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for (assignee, share_bps) in assignees.iter().zip([5_000, 3_000, 2_000]) {
+            task.assign_with_share(*assignee, share_bps).unwrap();
+        }
+        task.remove_assignee(&assignees[2]).unwrap();
+        task.open_dispute(&assignees[0]).unwrap();
+
+        task.resolve_dispute(0, None).unwrap();
+        assert!(task.assignee_shares.is_empty());
+        assert_eq!(task.payable_amounts, vec![500, 500]);
+    }
+
+    #[test]
+    fn resolving_a_completed_task_only_touches_unapproved_payouts() {
+        let (mut task, assignees) = arbitrated_task(1_000, 2);
+        task.snapshot_payable_amounts().unwrap();
+        task.status = TaskStatus::Completed;
+        approve(&mut task, &assignees[0]);
+        task.open_dispute(&assignees[1]).unwrap();
+        assert_err(task.resolve_dispute(501, None), AltruistError::InvalidDisputeSplit);
+
+        task.resolve_dispute(200, None).unwrap();
+        assert_eq!(task.payable_amounts, vec![500, 300]);
+        assert_eq!(task.reward_amount, 800);
+        assert!(task.check_can_claim(&assignees[1]).is_ok());
+    }
+
+    #[test]
+    fn kill_fee_applies_to_unreleased_reward_once_work_started() {
+        let (mut task, assignees) = task(1_000, 2);
+        task.kill_fee_bps = 2_500;
+        assert_eq!(task.kill_fee().unwrap(), 0);
+
+        task.status = TaskStatus::InProgress;
+        assert_eq!(task.kill_fee().unwrap(), 250);
+        // Accepted assignees stay until the task is cancelled and the fee paid
+        assert_err(task.remove_assignee(&assignees[0]), AltruistError::AssigneeRemovalLocked);
+        let invitee = Pubkey::new_unique();
+        task.invite(invitee, None).unwrap();
+        task.remove_assignee(&invitee).unwrap();
+
+        task.add_milestone([0; 32], 200).unwrap();
+        task.approve_milestone(0).unwrap();
+        assert_eq!(task.kill_fee().unwrap(), 200);
+
+        let (mut unassigned, _) = self::task(1_000, 0);
+        unassigned.kill_fee_bps = 2_500;
+        unassigned.status = TaskStatus::InProgress;
+        assert_eq!(unassigned.kill_fee().unwrap(), 0);
+    }
+
+    #[test]
+    fn invitations_only_count_once_accepted() {
+        let (mut task, _) = task(1_000, 0);
+        let (alice, bob) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        assert_err(task.invite(task.creator, None), AltruistError::CannotAssignToCreator);
+        task.invite(alice, None).unwrap();
+        task.invite(bob, None).unwrap();
+        assert_err(task.invite(alice, None), AltruistError::DuplicateAssignee);
+        assert!(task.is_invited(&alice) && !task.is_assignee(&alice));
+
+        task.accept_invitation(&alice).unwrap();
+        assert!(task.is_assignee(&alice) && !task.is_invited(&alice));
+        assert_eq!(task.submissions.len(), 1);
+
+        task.decline_invitation(&bob).unwrap();
+        assert_err(task.accept_invitation(&bob), AltruistError::NoPendingInvitation);
+        assert_eq!(task.assignees, vec![alice]);
+    }
+
+    #[test]
+    fn invitations_keep_one_share_mode() {
+        let (mut task, _) = task(1_000, 0);
+        task.invite(Pubkey::new_unique(), Some(6_000)).unwrap();
+        assert_err(task.invite(Pubkey::new_unique(), None), AltruistError::MixedShareModes);
+        assert_err(task.assign_to(Pubkey::new_unique()), AltruistError::MixedShareModes);
+
+        let (mut task, _) = self::task(1_000, 1);
+        assert_err(task.invite(Pubkey::new_unique(), Some(5_000)), AltruistError::MixedShareModes);
+        assert_err(
+            task.assign_with_share(Pubkey::new_unique(), 5_000),
+            AltruistError::InvalidAssigneeShares,
+        );
+    }
+
+    #[test]
+    fn weighted_invitations_apply_their_share_on_acceptance() {
+        let (mut task, _) = task(1_000, 0);
+        let invitees: Vec<Pubkey> = (0..2).map(|_| Pubkey::new_unique()).collect();
+        assert_err(
+            task.invite_multiple(invitees.clone(), Some(vec![6_000, 3_000])),
+            AltruistError::InvalidAssigneeShares,
+        );
+        task.invite_multiple(invitees.clone(), Some(vec![6_000, 4_000])).unwrap();
+
+        task.accept_invitation(&invitees[1]).unwrap();
+        task.accept_invitation(&invitees[0]).unwrap();
+        assert_eq!(task.assignees, vec![invitees[1], invitees[0]]);
+        assert_eq!(task.assignee_shares, vec![4_000, 6_000]);
+        assert!(task.validate_shares().is_ok());
+    }
+
+    #[test]
+    fn pending_invitations_hold_assignee_slots() {
+        let (mut task, _) = task(1_000, Task::MAX_ASSIGNEES - 1);
+        let invitee = Pubkey::new_unique();
+        task.invite(invitee, None).unwrap();
+
+        assert_err(task.invite(Pubkey::new_unique(), None), AltruistError::TooManyAssignees);
+        assert_err(task.assign_to(Pubkey::new_unique()), AltruistError::TooManyAssignees);
+
+        // Removing an invitee revokes the invitation and frees the slot
+        task.remove_assignee(&invitee).unwrap();
+        task.assign_to(Pubkey::new_unique()).unwrap();
+        assert_eq!(task.assignees.len(), Task::MAX_ASSIGNEES);
+    }
+
+    #[test]
+    fn applications_settle_only_once() {
+        let (task, applicant) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut application = Application {
+            task: Pubkey::default(),
+            applicant: Pubkey::default(),
+            status: ApplicationStatus::Pending,
+            created_at: 0,
+            updated_at: 0,
+            bump: 0,
+        };
+
+        application.open(task, applicant, 1, 255).unwrap();
+        assert_eq!((application.applicant, application.created_at), (applicant, 1));
+        assert_err(application.open(task, applicant, 2, 255), AltruistError::InvalidApplicationStatus);
+
+        application.settle(ApplicationStatus::Accepted, 7).unwrap();
+        assert_eq!((application.status, application.updated_at), (ApplicationStatus::Accepted, 7));
+        assert_err(
+            application.settle(ApplicationStatus::Withdrawn, 8),
+            AltruistError::InvalidApplicationStatus,
+        );
+
+        // Settled records stay on-chain and are re-opened when the applicant applies again
+        application.open(task, applicant, 9, 255).unwrap();
+        assert_eq!(application.status, ApplicationStatus::Pending);
+        assert_eq!((application.created_at, application.updated_at), (1, 9));
+    }
+}
@@ -8,6 +8,7 @@ use anchor_lang::{
         system_instruction::transfer,
         sysvar::Sysvar,
     },
+    system_program,
     Lamports,
 };
 use anchor_spl::token_interface::spl_token_2022::{
@@ -15,7 +16,7 @@ use anchor_spl::token_interface::spl_token_2022::{
     state::Mint,
 };
 use bytemuck::Pod;
-use spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList};
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 
@@ -24,7 +25,9 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
 ) -> Result<()> {
-    let extra_lamports = Rent::get()?.minimum_balance(account.data_len()) - account.get_lamports();
+    let extra_lamports = Rent::get()?
+        .minimum_balance(account.data_len())
+        .saturating_sub(account.get_lamports());
     if extra_lamports > 0 {
         invoke(
             &transfer(payer.key, account.key, extra_lamports),
@@ -32,4 +35,101 @@ pub fn update_account_lamports_to_minimum_balance<'info>(
         )?;
     }
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Lamports held by an account above its rent-exempt minimum
+pub fn lamports_above_rent(account: &AccountInfo) -> Result<u64> {
+    let rent_exempt = Rent::get()?.minimum_balance(account.data_len());
+    Ok(account.get_lamports().saturating_sub(rent_exempt))
+}
+
+/// Move lamports out of a program-owned account
+pub fn transfer_program_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    from.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+    Ok(())
+}
+
+/// Close a program-owned account that cannot be loaded through `Account<T>`,
+/// sending its lamports to `destination`
+pub fn close_program_account<'info>(
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+) -> Result<()> {
+    let account_lamports = account.get_lamports();
+    account.sub_lamports(account_lamports)?;
+    destination.add_lamports(account_lamports)?;
+
+    account.assign(&system_program::ID);
+    account.resize(0)?;
+    Ok(())
+}
+
+/// Extra accounts Token-2022 resolves for the transfer hook `execute` instruction:
+/// the faucet, then the participant registrations of the source and destination
+/// token account owners (the owner is stored at offset 32 of a token account)
+pub fn transfer_hook_extra_account_metas(faucet: &Pubkey) -> Result<Vec<ExtraAccountMeta>> {
+    let participant_seeds = |token_account_index: u8| {
+        [
+            Seed::Literal { bytes: b"transfer_participant".to_vec() },
+            Seed::AccountKey { index: 1 },
+            Seed::AccountData { account_index: token_account_index, data_index: 32, length: 32 },
+        ]
+    };
+    Ok(vec![
+        ExtraAccountMeta::new_with_pubkey(faucet, false, false)?,
+        ExtraAccountMeta::new_with_seeds(&participant_seeds(0), false, false)?,
+        ExtraAccountMeta::new_with_seeds(&participant_seeds(2), false, false)?,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transfer_hook_accounts_resolve_to_faucet_and_participants() {
+        let faucet = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let (source_owner, destination_owner) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        // Token accounts store their owner at offset 32
+        let token_account_data = |owner: &Pubkey| {
+            let mut data = vec![0u8; 165];
+            data[32..64].copy_from_slice(owner.as_ref());
+            data
+        };
+        let keys = [Pubkey::new_unique(), mint, Pubkey::new_unique(), source_owner];
+        let data = [
+            Some(token_account_data(&source_owner)),
+            None,
+            Some(token_account_data(&destination_owner)),
+            None,
+        ];
+
+        let resolved: Vec<Pubkey> = transfer_hook_extra_account_metas(&faucet)
+            .unwrap()
+            .iter()
+            .map(|meta| {
+                meta.resolve(&[], &crate::ID, |index| {
+                    keys.get(index).map(|key| (key, data[index].as_deref()))
+                })
+                .unwrap()
+                .pubkey
+            })
+            .collect();
+
+        let participant = |wallet: &Pubkey| {
+            Pubkey::find_program_address(
+                &[b"transfer_participant", mint.as_ref(), wallet.as_ref()],
+                &crate::ID,
+            )
+            .0
+        };
+        assert_eq!(resolved, vec![faucet, participant(&source_owner), participant(&destination_owner)]);
+    }
+}
@@ -0,0 +1,169 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{ed25519_program, instruction::Instruction},
+};
+
+use crate::errors::AltruistError;
+
+// Domain separation prefix so voucher signatures can't be replayed as other messages
+const VOUCHER_DOMAIN: &[u8] = b"altruvist-faucet-voucher";
+
+// Layout of the Ed25519 precompile instruction data (see solana_sdk::ed25519_instruction)
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// Message the voucher signer signs off-chain for a faucet claim
+pub fn voucher_message(faucet: &Pubkey, user: &Pubkey, amount: u64, expiry: i64, nonce: u64) -> Vec<u8> {
+    [
+        VOUCHER_DOMAIN,
+        faucet.as_ref(),
+        user.as_ref(),
+        &amount.to_le_bytes(),
+        &expiry.to_le_bytes(),
+        &nonce.to_le_bytes(),
+    ]
+    .concat()
+}
+
+/// Check that `ix` is an Ed25519 precompile instruction verifying exactly one
+/// signature by `signer` over `message`, with all data inline in the instruction
+pub fn verify_ed25519_instruction(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> Result<()> {
+    require!(ix.program_id == ed25519_program::ID, AltruistError::InvalidVoucherSignature);
+    require!(ix.accounts.is_empty(), AltruistError::InvalidVoucherSignature);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN && data[0] == 1,
+        AltruistError::InvalidVoucherSignature
+    );
+
+    let read_u16 = |offset: usize| -> usize {
+        u16::from_le_bytes([data[offset], data[offset + 1]]) as usize
+    };
+    let offsets = SIGNATURE_OFFSETS_START;
+    let signature_offset = read_u16(offsets);
+    let signature_ix_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4);
+    let pubkey_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8);
+    let message_size = read_u16(offsets + 10);
+    let message_ix_index = read_u16(offsets + 12);
+
+    // Data referenced from other instructions could be swapped out, so require it inline
+    let inline = u16::MAX as usize;
+    require!(
+        signature_ix_index == inline && pubkey_ix_index == inline && message_ix_index == inline,
+        AltruistError::InvalidVoucherSignature
+    );
+    require!(
+        signature_offset + SIGNATURE_LEN <= data.len(),
+        AltruistError::InvalidVoucherSignature
+    );
+
+    let signed_pubkey = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+        .ok_or(AltruistError::InvalidVoucherSignature)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(AltruistError::InvalidVoucherSignature)?;
+
+    require!(signed_pubkey == signer.as_ref(), AltruistError::InvalidVoucherSignature);
+    require!(signed_message == message, AltruistError::InvalidVoucherSignature);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Build Ed25519 precompile instruction data laid out like solana_sdk::ed25519_instruction
+    fn ed25519_ix(signer: &Pubkey, message: &[u8]) -> Instruction {
+        let inline = u16::MAX;
+        let pubkey_offset = SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN;
+        let signature_offset = pubkey_offset + PUBKEY_LEN;
+        let message_offset = signature_offset + SIGNATURE_LEN;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset as u16,
+            inline,
+            pubkey_offset as u16,
+            inline,
+            message_offset as u16,
+            message.len() as u16,
+            inline,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[7; SIGNATURE_LEN]);
+        data.extend_from_slice(message);
+
+        Instruction {
+            program_id: ed25519_program::ID,
+            accounts: Vec::new(),
+            data,
+        }
+    }
+
+    fn message() -> Vec<u8> {
+        voucher_message(&Pubkey::new_unique(), &Pubkey::new_unique(), 1_000, 1_700_000_000, 1)
+    }
+
+    #[test]
+    fn accepts_matching_signer_and_message() {
+        let signer = Pubkey::new_unique();
+        let message = message();
+
+        assert!(verify_ed25519_instruction(&ed25519_ix(&signer, &message), &signer, &message).is_ok());
+    }
+
+    #[test]
+    fn rejects_wrong_signer_or_message() {
+        let signer = Pubkey::new_unique();
+        let message = message();
+        let ix = ed25519_ix(&signer, &message);
+
+        assert!(verify_ed25519_instruction(&ix, &Pubkey::new_unique(), &message).is_err());
+        assert!(verify_ed25519_instruction(&ix, &signer, &self::message()).is_err());
+    }
+
+    #[test]
+    fn rejects_other_programs_and_multiple_signatures() {
+        let signer = Pubkey::new_unique();
+        let message = message();
+
+        let mut ix = ed25519_ix(&signer, &message);
+        ix.program_id = Pubkey::new_unique();
+        assert!(verify_ed25519_instruction(&ix, &signer, &message).is_err());
+
+        let mut ix = ed25519_ix(&signer, &message);
+        ix.data[0] = 2;
+        assert!(verify_ed25519_instruction(&ix, &signer, &message).is_err());
+    }
+
+    #[test]
+    fn rejects_data_from_other_instructions() {
+        let signer = Pubkey::new_unique();
+        let message = message();
+
+        // Point the message at instruction 0 instead of this instruction's data
+        let mut ix = ed25519_ix(&signer, &message);
+        let message_ix_index = SIGNATURE_OFFSETS_START + 12;
+        ix.data[message_ix_index..message_ix_index + 2].copy_from_slice(&0u16.to_le_bytes());
+        assert!(verify_ed25519_instruction(&ix, &signer, &message).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let signer = Pubkey::new_unique();
+        let message = message();
+
+        let mut ix = ed25519_ix(&signer, &message);
+        ix.data.truncate(ix.data.len() - 1);
+        assert!(verify_ed25519_instruction(&ix, &signer, &message).is_err());
+    }
+}